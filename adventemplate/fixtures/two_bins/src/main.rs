@@ -0,0 +1,7 @@
+fn main() {
+    println!("Part One:");
+    println!("  The number of positions where a beacon can't be is: 26");
+
+    println!("Part Two:");
+    println!("  The tuning frequency of the distress beacon is: 56000011");
+}
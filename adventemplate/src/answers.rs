@@ -0,0 +1,173 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use toml_edit::{DocumentMut, Item, Table};
+
+use crate::{
+    day_runs::DayRun,
+    error::{AdventemplateError, Result},
+};
+
+/// Where the known answers are kept, relative to the directory adventemplate is run in
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The known right answers for each part of each day, kept in `answers.toml` as e.g.
+///
+/// ```toml
+/// [2022.day1]
+/// part_one = "24000"
+/// part_two = "45000"
+/// ```
+pub struct AnswerBook {
+    path: PathBuf,
+    document: DocumentMut,
+}
+
+impl AnswerBook {
+    /// Read the answers, starting with none if the file isn't there yet
+    pub fn load(path: &Path) -> Result<Self> {
+        let document = match fs::read_to_string(path) {
+            Ok(answers) => answers
+                .parse::<DocumentMut>()
+                .map_err(|e| AdventemplateError::Input(format!("Couldn't parse {}: {e}", path.display())))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => DocumentMut::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(AnswerBook { path: path.to_path_buf(), document })
+    }
+
+    pub fn get(&self, year: &str, day_num: u8, part: u8) -> Option<&str> {
+        self.document
+            .get(year)?
+            .get(format!("day{day_num}"))?
+            .get(part_key(part))?
+            .as_str()
+    }
+
+    /// Set the answer for the part, returning whether that changed anything
+    pub fn set(&mut self, year: &str, day_num: u8, part: u8, answer: &str) -> bool {
+        if self.get(year, day_num, part) == Some(answer) {
+            return false;
+        }
+
+        // Years only hold days, so they're dotted into the day headers rather than getting a header of their own
+        let year_table = self.document.entry(year).or_insert_with(|| {
+            let mut year_table = Table::new();
+            year_table.set_implicit(true);
+            Item::Table(year_table)
+        });
+        let day_table = year_table
+            .as_table_mut()
+            .and_then(|year_table| year_table.entry(&format!("day{day_num}")).or_insert_with(toml_edit::table).as_table_mut());
+        match day_table {
+            Some(day_table) => {
+                day_table[part_key(part)] = toml_edit::value(answer);
+                true
+            }
+            // Someone's written something other than a table there, which is better left alone
+            None => false,
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, self.document.to_string())?;
+        Ok(())
+    }
+}
+
+fn part_key(part: u8) -> &'static str {
+    match part {
+        1 => "part_one",
+        _ => "part_two",
+    }
+}
+
+/// How a part's answer compared to the known one
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Matches,
+    Differs { expected: String, actual: Option<String> },
+    /// There's no known answer to compare against
+    Unknown,
+}
+
+/// Compare the answers from running a day against the known ones
+pub fn verify(answer_book: &AnswerBook, year: &str, day_run: &DayRun) -> [Verdict; 2] {
+    [(1, &day_run.part_one), (2, &day_run.part_two)].map(|(part, actual)| {
+        match (answer_book.get(year, day_run.day_num, part), actual) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Matches,
+            (Some(expected), actual) => Verdict::Differs {
+                expected: expected.to_string(),
+                actual: actual.clone(),
+            },
+        }
+    })
+}
+
+// region: Tests
+
+#[test]
+fn answers_are_stored_per_year_day_and_part() {
+    let root_dir = tempfile::tempdir().unwrap();
+    let answers_path = root_dir.path().join(ANSWERS_FILE);
+
+    let mut answer_book = AnswerBook::load(&answers_path).unwrap();
+    assert!(answer_book.set("2022", 1, 1, "24000"));
+    assert!(answer_book.set("2022", 1, 2, "45000"));
+    assert!(answer_book.set("2022", 10, 2, "##..\n#..#"));
+    assert!(!answer_book.set("2022", 1, 1, "24000"));
+    answer_book.save().unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&answers_path).unwrap(),
+        "[2022.day1]\npart_one = \"24000\"\npart_two = \"45000\"\n\n[2022.day10]\npart_two = \"\"\"\n##..\n#..#\"\"\"\n"
+    );
+    let answer_book = AnswerBook::load(&answers_path).unwrap();
+    assert_eq!(answer_book.get("2022", 10, 2), Some("##..\n#..#"));
+    assert_eq!(answer_book.get("2022", 10, 1), None);
+    assert_eq!(answer_book.get("2021", 1, 1), None);
+}
+
+#[test]
+fn runs_are_verified_against_the_known_answers() {
+    let root_dir = tempfile::tempdir().unwrap();
+    let mut answer_book = AnswerBook::load(&root_dir.path().join(ANSWERS_FILE)).unwrap();
+    answer_book.set("2022", 14, 1, "832");
+    answer_book.set("2022", 14, 2, "27601");
+    let day_run = DayRun {
+        day_num: 14,
+        project_name: String::from("day14-regolith-reservoir"),
+        part_one: Some(String::from("832")),
+        part_two: Some(String::from("27600")),
+        duration: None,
+        error: None,
+    };
+
+    assert_eq!(
+        verify(&answer_book, "2022", &day_run),
+        [
+            Verdict::Matches,
+            Verdict::Differs { expected: String::from("27601"), actual: Some(String::from("27600")) },
+        ]
+    );
+    assert_eq!(verify(&answer_book, "2021", &day_run), [Verdict::Unknown, Verdict::Unknown]);
+}
+
+#[test]
+fn days_with_more_than_one_binary_verify() {
+    let project_dir = crate::day_runs::two_bin_project();
+    let mut answer_book = AnswerBook::load(&project_dir.path().join(ANSWERS_FILE)).unwrap();
+    answer_book.set("2022", 15, 1, "26");
+    answer_book.set("2022", 15, 2, "56000011");
+
+    // Cargo reports the binaries in a different order from one build to the next, so a lucky run isn't enough
+    for _ in 0..3 {
+        let day_run = crate::day_runs::run_day(&crate::command_runner::SystemRunner, 15, project_dir.path());
+        assert_eq!(verify(&answer_book, "2022", &day_run), [Verdict::Matches, Verdict::Matches]);
+    }
+}
+
+// endregion
@@ -0,0 +1,146 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use reqwest::{blocking::Client, header};
+
+use crate::error::Result;
+
+/// The real Advent of Code site, used unless another base URL is given (say, a local stand-in server for tests)
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable checked first for the session token
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Identify the tool (and where to find its author) to the site, as the Advent of Code folks ask scripts to do
+pub const USER_AGENT: &str = concat!(
+    "adventemplate/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/lukependleton/advent-of-code-2022)"
+);
+
+/// What actually sends requests. The site only needs GETs and form POSTs, so that's all there is, which keeps stand-ins for
+///  tests simple
+pub trait HttpClient {
+    /// GET the url with the headers, returning the body as text if the response was a success
+    fn get(&self, url: &str, headers: &[(&str, String)]) -> Result<String>;
+
+    /// POST the form to the url with the headers, returning the body as text if the response was a success
+    fn post_form(&self, url: &str, headers: &[(&str, String)], form: &[(&str, &str)]) -> Result<String>;
+}
+
+/// Requests sent over the network with reqwest
+pub struct ReqwestClient;
+
+impl HttpClient for ReqwestClient {
+    fn get(&self, url: &str, headers: &[(&str, String)]) -> Result<String> {
+        let mut request = Client::new().get(url);
+        for (name, value) in headers {
+            request = request.header(*name, value);
+        }
+        Ok(request
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.text())?)
+    }
+
+    fn post_form(&self, url: &str, headers: &[(&str, String)], form: &[(&str, &str)]) -> Result<String> {
+        let mut request = Client::new().post(url).form(form);
+        for (name, value) in headers {
+            request = request.header(*name, value);
+        }
+        Ok(request
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.text())?)
+    }
+}
+
+/// A small http client for the Advent of Code site that sends the session cookie (when there is one) along with every request
+pub struct AocClient {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub http: Box<dyn HttpClient>,
+}
+
+impl AocClient {
+    /// A client for the site at the base url that sends its requests over the network
+    pub fn new(base_url: String, session: Option<String>) -> Self {
+        AocClient { base_url, session, http: Box::new(ReqwestClient) }
+    }
+
+    /// The full url for the given path on the site, e.g. `/2022/day/6`
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url.trim_end_matches('/'))
+    }
+
+    /// GET the given path, returning the body as text if the site responded successfully
+    pub fn get_text(&self, path: &str) -> Result<String> {
+        self.http.get(&self.url(path), &self.headers())
+    }
+
+    /// POST the form to the given path, returning the body as text if the site responded successfully
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        self.http.post_form(&self.url(path), &self.headers(), form)
+    }
+
+    fn headers(&self) -> Vec<(&'static str, String)> {
+        let mut headers = vec![(header::USER_AGENT.as_str(), String::from(USER_AGENT))];
+        if let Some(session) = &self.session {
+            headers.push((header::COOKIE.as_str(), format!("session={session}")));
+        }
+        headers
+    }
+}
+
+/// Where the session token is read from when it isn't in the environment
+pub fn default_session_file() -> Option<PathBuf> {
+    dirs::config_dir().map(|config_dir| config_dir.join("adventemplate").join("session"))
+}
+
+/// Look up the session token, first in the `AOC_SESSION` environment variable and then in the given session file
+pub fn read_session_token(session_file: Option<&Path>) -> Option<String> {
+    session_token_from(env::var(SESSION_ENV_VAR).ok(), session_file)
+}
+
+fn session_token_from(env_value: Option<String>, session_file: Option<&Path>) -> Option<String> {
+    env_value
+        .or_else(|| session_file.and_then(|session_file| fs::read_to_string(session_file).ok()))
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+// region: Tests
+
+#[cfg(test)]
+use crate::test_server::TestServer;
+
+#[test]
+fn requests_carry_the_session_cookie_and_user_agent() {
+    let server = TestServer::start([("/2022/day/1/input", (200, "1000\n2000\n"))]);
+    let client = AocClient::new(server.base_url(), Some(String::from("53616c7465645f5f")));
+
+    assert_eq!(client.get_text("/2022/day/1/input").unwrap(), "1000\n2000\n");
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("Cookie"), Some("session=53616c7465645f5f"));
+    assert_eq!(request.header("User-Agent"), Some(USER_AGENT));
+}
+
+#[test]
+fn session_token_prefers_the_environment_over_the_file() {
+    let config_dir = tempfile::tempdir().unwrap();
+    let session_file = config_dir.path().join("session");
+    fs::write(&session_file, "from-file\n").unwrap();
+
+    assert_eq!(
+        session_token_from(Some(String::from("from-env")), Some(&session_file)),
+        Some(String::from("from-env"))
+    );
+    assert_eq!(session_token_from(None, Some(&session_file)), Some(String::from("from-file")));
+    assert_eq!(session_token_from(Some(String::from("  ")), None), None);
+    assert_eq!(session_token_from(None, Some(&config_dir.path().join("missing"))), None);
+}
+
+// endregion
@@ -0,0 +1,87 @@
+use std::{io, path::Path, process::Command};
+#[cfg(test)]
+use std::{cell::RefCell, fs, path::PathBuf, rc::Rc};
+
+/// How running a program went
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommandOutput {
+    pub success: bool,
+    /// The exit status as it'd be shown to the user, e.g. `exit status: 101`
+    pub status: String,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// What the setup steps (and run, verify and submit) run other programs (cargo and git) through, so tests can stand in for
+///  them
+pub trait CommandRunner {
+    /// Run the program with the arguments in the directory until it finishes, capturing what it printed. Only failing to
+    ///  start the program is an error - a program that runs and fails comes back as an unsuccessful output
+    fn run(&self, program: &str, args: &[&str], dir: &Path) -> io::Result<CommandOutput>;
+}
+
+/// Runs the programs for real
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&self, program: &str, args: &[&str], dir: &Path) -> io::Result<CommandOutput> {
+        let output = Command::new(program).args(args).current_dir(dir).output()?;
+        Ok(CommandOutput {
+            success: output.status.success(),
+            status: output.status.to_string(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+}
+
+/// Stands in for cargo and git, noting each command it's given. `cargo new` lays out a bare project like the real one and
+///  git just succeeds, as if in a clean repo with something to commit. Anything else prints whatever it's been given to for
+///  the command, or nothing
+#[cfg(test)]
+#[derive(Clone, Default)]
+pub(crate) struct FakeRunner {
+    /// Each command that was run, with the directory it was run in
+    pub commands: Rc<RefCell<Vec<(String, PathBuf)>>>,
+    /// Commands starting with this fail
+    pub failing: Option<&'static str>,
+    /// What commands starting with each of these print
+    pub outputs: Vec<(&'static str, String)>,
+}
+
+#[cfg(test)]
+impl FakeRunner {
+    pub fn commands(&self) -> Vec<String> {
+        self.commands.borrow().iter().map(|(command, _)| command.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+impl CommandRunner for FakeRunner {
+    fn run(&self, program: &str, args: &[&str], dir: &Path) -> io::Result<CommandOutput> {
+        let command = format!("{program} {}", args.join(" "));
+        self.commands.borrow_mut().push((command.clone(), dir.to_path_buf()));
+        let succeeded = |stdout: &str| Ok(CommandOutput { success: true, status: String::from("exit status: 0"), stdout: stdout.to_string(), ..Default::default() });
+        let failed = || Ok(CommandOutput { status: String::from("exit status: 1"), stderr: String::from("error: it broke"), ..Default::default() });
+
+        if self.failing.is_some_and(|failing| command.starts_with(failing)) {
+            return failed();
+        }
+        if let Some((_, stdout)) = self.outputs.iter().find(|(prefix, _)| command.starts_with(prefix)) {
+            return succeeded(stdout);
+        }
+        match args {
+            ["new", project_path, ..] if program == "cargo" => {
+                let project_dir = dir.join(project_path);
+                fs::create_dir_all(project_dir.join("src"))?;
+                fs::write(project_dir.join("Cargo.toml"), "[package]\n")?;
+                fs::write(project_dir.join("src").join("main.rs"), "fn main() {\n    println!(\"Hello, world!\");\n}\n")?;
+                succeeded("")
+            }
+            ["branch", "--show-current"] => succeeded("main\n"),
+            // There's no branch for the day yet, and there's always something staged
+            ["rev-parse", "--verify", ..] | ["diff", "--cached", "--quiet"] => failed(),
+            _ => succeeded(""),
+        }
+    }
+}
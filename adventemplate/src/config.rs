@@ -0,0 +1,278 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use toml_edit::{DocumentMut, Item};
+
+use crate::{
+    error::{AdventemplateError, Result},
+    privacy,
+    workspace::ProjectLayout,
+};
+
+/// The name of the config file, looked for in the root of the repo and then in the user's config directory
+pub const CONFIG_FILE: &str = "adventemplate.toml";
+
+/// Per-user defaults for adventemplate, read from an `adventemplate.toml` like
+///
+/// ```toml
+/// year = 2023
+/// layout = "year"
+/// steps = ["cargo", "gitignore", "readme", "inputs", "examples", "main", "workspace"]
+/// template_dir = "my-templates"
+/// gitignore = ["/target", "/.adventemplate", "/inputs/question"]
+/// git_add = true
+/// private_files = ["inputs/*"]
+///
+/// [inputs]
+/// example = "example"
+/// question = "input.txt"
+/// ```
+///
+/// Anything left out keeps its usual default, and anything given on the command line goes over what's here
+#[derive(Debug, PartialEq, Eq)]
+pub struct Config {
+    pub year: Option<u16>,
+    pub layout: Option<ProjectLayout>,
+    /// The steps that run when neither `--only` nor `--skip` say otherwise (optional ones included)
+    pub steps: Option<Vec<String>>,
+    /// Where the template flavours are, resolved against the directory the config file is in
+    pub template_dir: Option<PathBuf>,
+    pub inputs: InputFiles,
+    /// The lines of the `.gitignore` the gitignore step writes. Besides the build output and adventemplate's own files, the
+    ///  puzzle input is ignored by default
+    pub gitignore: Vec<String>,
+    /// Whether to `git add` each project once its steps have run
+    pub git_add: bool,
+    /// The files in a project that are never staged (patterns relative to the project). Just the puzzle input by default,
+    ///  since the puzzle inputs aren't meant to be shared
+    pub private_files: Vec<String>,
+}
+
+/// The names of the files in a project's `inputs` folder
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputFiles {
+    /// The main example (any further examples get a number on the end, e.g. `example2`)
+    pub example: String,
+    /// The puzzle input that gets downloaded
+    pub question: String,
+}
+
+impl Default for InputFiles {
+    fn default() -> Self {
+        InputFiles {
+            example: String::from("example"),
+            question: String::from("question"),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: None,
+            layout: None,
+            steps: None,
+            template_dir: None,
+            inputs: InputFiles::default(),
+            gitignore: default_gitignore(&InputFiles::default()),
+            git_add: false,
+            private_files: vec![String::from("inputs/question")],
+        }
+    }
+}
+
+impl Config {
+    /// Find the config file (in the root of the repo the start directory is in, then in the user config directory) and
+    ///  read the first one there is, along with where it was. Without a config file, everything is left at its default
+    pub fn find(start_dir: &Path, user_config_dir: Option<&Path>) -> Result<(Self, Option<PathBuf>)> {
+        let candidates = [
+            Some(repo_root(start_dir).join(CONFIG_FILE)),
+            user_config_dir.map(|config_dir| config_dir.join("adventemplate").join(CONFIG_FILE)),
+        ];
+        for config_path in candidates.into_iter().flatten() {
+            match fs::read_to_string(&config_path) {
+                Ok(config_toml) => return Ok((Config::parse(&config_toml, &config_path)?, Some(config_path))),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok((Config::default(), None))
+    }
+
+    /// Read the config from the contents of the file at `config_path`
+    pub fn parse(config_toml: &str, config_path: &Path) -> Result<Self> {
+        let invalid = |reason: String| AdventemplateError::Input(format!("Invalid config in {}: {reason}", config_path.display()));
+        let document = config_toml.parse::<DocumentMut>().map_err(|e| invalid(e.to_string()))?;
+
+        let mut config = Config::default();
+        let mut gitignore = None;
+        let mut private_files = None;
+        for (key, item) in document.iter() {
+            match key {
+                "year" => {
+                    let year = item.as_integer().and_then(|year| u16::try_from(year).ok());
+                    config.year = Some(year.ok_or_else(|| invalid(String::from("'year' should be a number, e.g. 2022")))?);
+                }
+                "layout" => {
+                    let layout = item.as_str().and_then(|layout| ProjectLayout::from_str(layout, true).ok());
+                    config.layout = Some(layout.ok_or_else(|| invalid(String::from("'layout' should be \"flat\" or \"year\"")))?);
+                }
+                "steps" => config.steps = Some(string_list(item).ok_or_else(|| invalid(String::from("'steps' should be a list of step names")))?),
+                "template_dir" => {
+                    let template_dir = item.as_str().ok_or_else(|| invalid(String::from("'template_dir' should be a path")))?;
+                    let config_dir = config_path.parent().unwrap_or(Path::new("."));
+                    config.template_dir = Some(config_dir.join(template_dir));
+                }
+                "gitignore" => {
+                    gitignore = Some(string_list(item).ok_or_else(|| invalid(String::from("'gitignore' should be a list of rules")))?);
+                }
+                "git_add" => config.git_add = item.as_bool().ok_or_else(|| invalid(String::from("'git_add' should be true or false")))?,
+                "private_files" => {
+                    private_files = Some(string_list(item).ok_or_else(|| invalid(String::from("'private_files' should be a list of paths")))?);
+                }
+                "inputs" => {
+                    let inputs = item.as_table_like().ok_or_else(|| invalid(String::from("'inputs' should be a table")))?;
+                    for (input_key, input_item) in inputs.iter() {
+                        let file_name = input_item
+                            .as_str()
+                            .filter(|file_name| is_file_name(file_name))
+                            .ok_or_else(|| invalid(format!("'inputs.{input_key}' should be a file name")))?;
+                        match input_key {
+                            "example" => config.inputs.example = file_name.to_string(),
+                            "question" => config.inputs.question = file_name.to_string(),
+                            _ => return Err(invalid(format!("'inputs.{input_key}' isn't a setting (there's 'example' and 'question')"))),
+                        }
+                    }
+                }
+                _ => return Err(invalid(format!("'{key}' isn't a setting"))),
+            }
+        }
+        // Whatever the puzzle input is called, it stays private unless the config says otherwise
+        config.gitignore = gitignore.unwrap_or_else(|| default_gitignore(&config.inputs));
+        config.private_files = private_files.unwrap_or_else(|| vec![format!("inputs/{}", config.inputs.question)]);
+        Ok(config)
+    }
+}
+
+fn default_gitignore(inputs: &InputFiles) -> Vec<String> {
+    vec![String::from("/target"), String::from("/.adventemplate"), privacy::input_gitignore_rule(&inputs.question)]
+}
+
+/// The root of the git repo the directory is in, or the directory itself when it isn't in one. A relative directory is
+///  taken from the current directory first, since the ancestors of a path like `.` stop at the path itself
+fn repo_root(start_dir: &Path) -> PathBuf {
+    let start_dir = std::path::absolute(start_dir).unwrap_or_else(|_| start_dir.to_path_buf());
+    start_dir
+        .ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .unwrap_or(&start_dir)
+        .to_path_buf()
+}
+
+fn string_list(item: &Item) -> Option<Vec<String>> {
+    item.as_array()?.iter().map(|value| value.as_str().map(String::from)).collect()
+}
+
+/// Input files go straight in the inputs folder, so their names can't point anywhere else
+fn is_file_name(file_name: &str) -> bool {
+    !file_name.is_empty() && file_name != "." && file_name != ".." && !file_name.contains(['/', '\\'])
+}
+
+// region: Tests
+
+#[test]
+fn every_setting_is_read() {
+    let config_path = Path::new("aoc").join(CONFIG_FILE);
+    let config = Config::parse(
+        "year = 2023\nlayout = \"Year\"\nsteps = [\"cargo\", \"inputs\", \"workspace\"]\ntemplate_dir = \"my-templates\"\n\
+         gitignore = [\"/target\", \"/inputs\"]\ngit_add = true\n\n[inputs]\nquestion = \"input.txt\"\n",
+        &config_path,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config,
+        Config {
+            year: Some(2023),
+            layout: Some(ProjectLayout::Year),
+            steps: Some(vec![String::from("cargo"), String::from("inputs"), String::from("workspace")]),
+            template_dir: Some(Path::new("aoc").join("my-templates")),
+            inputs: InputFiles { example: String::from("example"), question: String::from("input.txt") },
+            gitignore: vec![String::from("/target"), String::from("/inputs")],
+            git_add: true,
+            private_files: vec![String::from("inputs/input.txt")],
+        }
+    );
+}
+
+#[test]
+fn an_empty_config_keeps_the_defaults() {
+    assert_eq!(Config::parse("", Path::new(CONFIG_FILE)).unwrap(), Config::default());
+}
+
+#[test]
+fn the_puzzle_input_is_ignored_whatever_it_is_called() {
+    let config = Config::parse("[inputs]\nquestion = \"input.txt\"\n", Path::new(CONFIG_FILE)).unwrap();
+
+    assert_eq!(config.gitignore, ["/target", "/.adventemplate", "/inputs/input.txt"]);
+    assert_eq!(config.private_files, ["inputs/input.txt"]);
+}
+
+#[test]
+fn bad_settings_are_an_input_error() {
+    for config_toml in [
+        "year = \"twenty twenty-two\"",
+        "layout = \"nested\"",
+        "steps = \"cargo\"",
+        "git_add = \"yes\"",
+        "private_files = [1, 2]",
+        "colour = \"red\"",
+        "[inputs]\nquestion = \"../question\"",
+        "[inputs]\nanswer = \"answer\"",
+        "year = ",
+    ] {
+        assert!(matches!(Config::parse(config_toml, Path::new(CONFIG_FILE)), Err(AdventemplateError::Input(_))), "{config_toml}");
+    }
+}
+
+#[test]
+fn repo_root_config_comes_before_the_user_config() {
+    let root_dir = tempfile::tempdir().unwrap();
+    let repo_dir = root_dir.path().join("advent-of-code");
+    let start_dir = repo_dir.join("2022");
+    let user_config_dir = root_dir.path().join("config");
+    fs::create_dir_all(repo_dir.join(".git")).unwrap();
+    fs::create_dir_all(&start_dir).unwrap();
+    fs::create_dir_all(user_config_dir.join("adventemplate")).unwrap();
+
+    // Without a config in the repo, the user's one is used
+    fs::write(user_config_dir.join("adventemplate").join(CONFIG_FILE), "year = 2021\n").unwrap();
+    let (config, config_path) = Config::find(&start_dir, Some(&user_config_dir)).unwrap();
+    assert_eq!(config.year, Some(2021));
+    assert_eq!(config_path, Some(user_config_dir.join("adventemplate").join(CONFIG_FILE)));
+
+    // The repo's config goes over it, even from a folder inside the repo
+    fs::write(repo_dir.join(CONFIG_FILE), "year = 2023\n").unwrap();
+    let (config, config_path) = Config::find(&start_dir, Some(&user_config_dir)).unwrap();
+    assert_eq!(config.year, Some(2023));
+    assert_eq!(config_path, Some(repo_dir.join(CONFIG_FILE)));
+
+    // And with no config anywhere, it's all defaults
+    assert_eq!(Config::find(&user_config_dir, None).unwrap(), (Config::default(), None));
+}
+
+#[test]
+fn the_repo_root_is_found_from_a_relative_directory() {
+    // Tests run in the crate's directory, so relative paths start from there and should end up at the same root as the full
+    //  paths do (whether or not the crate is in a repo)
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    assert_eq!(repo_root(Path::new(".")), repo_root(crate_dir));
+    assert_eq!(repo_root(Path::new("fixtures/two_bins/src")), repo_root(&crate_dir.join("fixtures/two_bins/src")));
+    assert!(repo_root(Path::new("fixtures/two_bins/src")).is_absolute());
+}
+
+// endregion
@@ -0,0 +1,349 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use toml_edit::DocumentMut;
+
+use crate::{
+    command_runner::CommandRunner,
+    error::{AdventemplateError, Result},
+    submission,
+};
+
+/// How the table of results is printed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// A table lined up for reading in the terminal
+    #[default]
+    Terminal,
+    Json,
+    /// A table that can be pasted into a README
+    Markdown,
+}
+
+/// How running one day's solution went
+#[derive(Debug)]
+pub struct DayRun {
+    pub day_num: u8,
+    pub project_name: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+    /// How long the solution took to run, not counting building it
+    pub duration: Option<Duration>,
+    pub error: Option<String>,
+}
+
+/// Build the day's project in release mode, then run it (timing just the run) and pick the answers out of what it printed
+pub fn run_day(runner: &dyn CommandRunner, day_num: u8, project_path: &Path) -> DayRun {
+    let project_name = project_path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut day_run = DayRun {
+        day_num,
+        project_name,
+        part_one: None,
+        part_two: None,
+        duration: None,
+        error: None,
+    };
+
+    let output = build_release(runner, project_path).and_then(|executable| {
+        let start = Instant::now();
+        let output = runner
+            .run(&executable.to_string_lossy(), &[], project_path)
+            .map_err(|e| AdventemplateError::Cargo(format!("Failed to run {}: {e}", executable.display())))?;
+        day_run.duration = Some(start.elapsed());
+        if !output.success {
+            return Err(AdventemplateError::Cargo(format!("The solution failed ({}): {}", output.status, output.stderr.trim())));
+        }
+        Ok(output.stdout)
+    });
+
+    match output {
+        Ok(output) => {
+            day_run.part_one = submission::scrape_answer(&output, 1);
+            day_run.part_two = submission::scrape_answer(&output, 2);
+        }
+        Err(e) => day_run.error = Some(e.to_string()),
+    }
+    day_run
+}
+
+/// Build the project's binary in release mode, returning where cargo put it
+fn build_release(runner: &dyn CommandRunner, project_path: &Path) -> Result<PathBuf> {
+    let bin_name = default_bin_name(project_path)?;
+    let output = runner
+        .run("cargo", &["build", "--release", "--quiet", "--message-format=json-render-diagnostics"], project_path)
+        .map_err(|e| AdventemplateError::Cargo(format!("Failed to run 'cargo build': {e}")))?;
+    if !output.success {
+        return Err(AdventemplateError::Cargo(format!("'cargo build' failed ({})", output.status)));
+    }
+
+    find_executable(&output.stdout, &bin_name)
+        .ok_or_else(|| AdventemplateError::Cargo(format!("'cargo build' didn't build the '{bin_name}' binary")))
+}
+
+/// The binary `cargo run` would pick for the project: its `default-run` if it has one, otherwise the one named after the
+///  package
+fn default_bin_name(project_path: &Path) -> Result<String> {
+    let manifest_path = project_path.join("Cargo.toml");
+    let invalid = |reason: String| AdventemplateError::Cargo(format!("Invalid manifest {}: {reason}", manifest_path.display()));
+    let manifest = fs::read_to_string(&manifest_path)?.parse::<DocumentMut>().map_err(|e| invalid(e.to_string()))?;
+
+    let package = &manifest["package"];
+    package
+        .get("default-run")
+        .or_else(|| package.get("name"))
+        .and_then(|name| name.as_str())
+        .map(String::from)
+        .ok_or_else(|| invalid(String::from("it doesn't have a package name")))
+}
+
+/// Pick the binary out of the JSON messages from `cargo build`. Cargo doesn't report the artifacts in any particular order,
+///  so it has to be found by name when the project has more than one (like day 15 with its `animate` binary)
+fn find_executable(cargo_output: &str, bin_name: &str) -> Option<PathBuf> {
+    let binaries = cargo_output
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|message| message["reason"] == "compiler-artifact")
+        .filter(|message| message["target"]["kind"].as_array().is_some_and(|kinds| kinds.iter().any(|kind| kind == "bin")))
+        .filter_map(|message| {
+            let executable = message["executable"].as_str()?;
+            Some((message["target"]["name"].as_str()?.to_string(), PathBuf::from(executable)))
+        })
+        .collect::<Vec<_>>();
+
+    match binaries.as_slice() {
+        [(_, executable)] => Some(executable.clone()),
+        _ => binaries.into_iter().find(|(name, _)| name == bin_name).map(|(_, executable)| executable),
+    }
+}
+
+/// Lay out the runs in the given format
+pub fn format_runs(day_runs: &[DayRun], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Terminal => format_table(day_runs, false),
+        OutputFormat::Markdown => format_table(day_runs, true),
+        OutputFormat::Json => {
+            let runs = day_runs
+                .iter()
+                .map(|day_run| {
+                    json!({
+                        "day": day_run.day_num,
+                        "project": day_run.project_name,
+                        "part_one": day_run.part_one,
+                        "part_two": day_run.part_two,
+                        "duration_ms": day_run.duration.map(|duration| duration.as_secs_f64() * 1000.0),
+                        "error": day_run.error,
+                    })
+                })
+                .collect::<Vec<_>>();
+            serde_json::to_string_pretty(&runs).unwrap()
+        }
+    }
+}
+
+/// A table with a row per day, with the columns padded to line up (markdown tables get the `|` borders as well)
+fn format_table(day_runs: &[DayRun], markdown: bool) -> String {
+    let header = ["Day", "Project", "Part One", "Part Two", "Time"].map(String::from);
+    let rows = day_runs
+        .iter()
+        .map(|day_run| {
+            [
+                day_run.day_num.to_string(),
+                day_run.project_name.clone(),
+                table_answer(day_run, &day_run.part_one),
+                table_answer(day_run, &day_run.part_two),
+                day_run.duration.map(format_duration).unwrap_or_else(|| String::from("-")),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let widths = (0..header.len())
+        .map(|column| rows.iter().chain([&header]).map(|row| row[column].chars().count()).max().unwrap_or_default())
+        .collect::<Vec<_>>();
+    let format_row = |row: &[String; 5]| {
+        let cells = row.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).collect::<Vec<_>>();
+        match markdown {
+            true => format!("| {} |", cells.join(" | ")),
+            false => cells.join("  ").trim_end().to_string(),
+        }
+    };
+
+    let mut lines = vec![format_row(&header)];
+    if markdown {
+        lines.push(format!("|{}|", widths.iter().map(|width| "-".repeat(width + 2)).collect::<Vec<_>>().join("|")));
+    }
+    lines.extend(rows.iter().map(format_row));
+    lines.join("\n")
+}
+
+/// What goes in a table cell for an answer: answers over several lines (like day 10's screen) don't fit, so just say how big
+///  they are
+fn table_answer(day_run: &DayRun, answer: &Option<String>) -> String {
+    match (answer, &day_run.error) {
+        (Some(answer), _) if answer.contains('\n') => format!("({} lines)", answer.lines().count()),
+        (Some(answer), _) => answer.clone(),
+        (None, Some(_)) => String::from("error"),
+        (None, None) => String::from("-"),
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    match duration.as_secs_f64() {
+        seconds if seconds >= 1.0 => format!("{seconds:.2}s"),
+        seconds => format!("{:.1}ms", seconds * 1000.0),
+    }
+}
+
+// region: Tests
+
+#[cfg(test)]
+fn test_runs() -> Vec<DayRun> {
+    vec![
+        DayRun {
+            day_num: 1,
+            project_name: String::from("day1-calorie-counting"),
+            part_one: Some(String::from("24000")),
+            part_two: Some(String::from("45000")),
+            duration: Some(Duration::from_micros(1500)),
+            error: None,
+        },
+        DayRun {
+            day_num: 10,
+            project_name: String::from("day10-cathode-ray-tube"),
+            part_one: Some(String::from("13140")),
+            part_two: Some(String::from("##..\n#..#")),
+            duration: Some(Duration::from_millis(2250)),
+            error: None,
+        },
+        DayRun {
+            day_num: 11,
+            project_name: String::from("day11-monkey-in-the-middle"),
+            part_one: None,
+            part_two: None,
+            duration: None,
+            error: Some(String::from("Cargo error: 'cargo build' failed")),
+        },
+    ]
+}
+
+/// Write out a project with two binaries, where the one `cargo run` picks (through `default-run`) prints the answers and
+///  the other doesn't
+#[cfg(test)]
+pub(crate) fn two_bin_project() -> tempfile::TempDir {
+    let project_dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(project_dir.path().join("src").join("bin")).unwrap();
+    fs::write(project_dir.path().join("Cargo.toml"), include_str!("../fixtures/two_bins/Cargo.toml")).unwrap();
+    fs::write(project_dir.path().join("src").join("main.rs"), include_str!("../fixtures/two_bins/src/main.rs")).unwrap();
+    fs::write(project_dir.path().join("src").join("bin").join("animate.rs"), include_str!("../fixtures/two_bins/src/bin/animate.rs")).unwrap();
+    project_dir
+}
+
+#[cfg(test)]
+use crate::command_runner::{FakeRunner, SystemRunner};
+
+#[cfg(test)]
+fn artifact_message(kind: &str, name: &str, executable: Option<&str>) -> String {
+    json!({ "reason": "compiler-artifact", "target": { "kind": [kind], "name": name }, "executable": executable }).to_string()
+}
+
+#[test]
+fn the_default_binary_is_picked_whatever_order_cargo_reports_it_in() {
+    let main = artifact_message("bin", "day15-beacon-exclusion-zone", Some("/target/release/day15-beacon-exclusion-zone"));
+    let animate = artifact_message("bin", "animate", Some("/target/release/animate"));
+    let library = artifact_message("lib", "day15_beacon_exclusion_zone", None);
+    let expected = Some(PathBuf::from("/target/release/day15-beacon-exclusion-zone"));
+
+    assert_eq!(find_executable(&[library.as_str(), &main, &animate].join("\n"), "day15-beacon-exclusion-zone"), expected);
+    assert_eq!(find_executable(&[animate.as_str(), &main, &library].join("\n"), "day15-beacon-exclusion-zone"), expected);
+    assert_eq!(find_executable(&animate, "day15-beacon-exclusion-zone"), Some(PathBuf::from("/target/release/animate")));
+    assert_eq!(find_executable(&library, "day15-beacon-exclusion-zone"), None);
+}
+
+#[test]
+fn projects_with_two_binaries_run_the_default_one() {
+    let project_dir = two_bin_project();
+
+    assert_eq!(default_bin_name(project_dir.path()).unwrap(), "day15-two-bins");
+    let day_run = run_day(&SystemRunner, 15, project_dir.path());
+
+    assert_eq!(day_run.error, None);
+    assert_eq!(day_run.part_one.as_deref(), Some("26"));
+    assert_eq!(day_run.part_two.as_deref(), Some("56000011"));
+}
+
+#[test]
+fn days_are_built_then_run() {
+    let project_dir = two_bin_project();
+    let cargo_output = [
+        artifact_message("bin", "day15-two-bins", Some("/target/release/day15-two-bins")),
+        artifact_message("bin", "animate", Some("/target/release/animate")),
+    ]
+    .join("\n");
+    let runner = FakeRunner {
+        outputs: vec![
+            ("cargo build", cargo_output),
+            ("/target/release/day15-two-bins", String::from("Part One:\n  The result is: 26\nPart Two:\n  The result is: 56000011\n")),
+        ],
+        ..Default::default()
+    };
+
+    let day_run = run_day(&runner, 15, project_dir.path());
+
+    assert_eq!(
+        runner.commands(),
+        ["cargo build --release --quiet --message-format=json-render-diagnostics", "/target/release/day15-two-bins "]
+    );
+    assert_eq!((day_run.part_one.as_deref(), day_run.part_two.as_deref()), (Some("26"), Some("56000011")));
+    assert!(day_run.duration.is_some());
+    assert_eq!(day_run.error, None);
+}
+
+#[test]
+fn build_and_run_failures_are_reported_in_the_run() {
+    let project_dir = two_bin_project();
+
+    let day_run = run_day(&FakeRunner { failing: Some("cargo build"), ..Default::default() }, 15, project_dir.path());
+    assert_eq!(day_run.error.as_deref(), Some("Cargo error: 'cargo build' failed (exit status: 1)"));
+    assert_eq!(day_run.part_one, None);
+
+    let runner = FakeRunner {
+        failing: Some("/target/release/day15-two-bins"),
+        outputs: vec![("cargo build", artifact_message("bin", "day15-two-bins", Some("/target/release/day15-two-bins")))],
+        ..Default::default()
+    };
+    let day_run = run_day(&runner, 15, project_dir.path());
+    assert_eq!(day_run.error.as_deref(), Some("Cargo error: The solution failed (exit status: 1): error: it broke"));
+}
+
+#[test]
+fn runs_format_as_a_markdown_table() {
+    assert_eq!(
+        format_runs(&test_runs(), OutputFormat::Markdown),
+        "\
+| Day | Project                    | Part One | Part Two  | Time  |
+|-----|----------------------------|----------|-----------|-------|
+| 1   | day1-calorie-counting      | 24000    | 45000     | 1.5ms |
+| 10  | day10-cathode-ray-tube     | 13140    | (2 lines) | 2.25s |
+| 11  | day11-monkey-in-the-middle | error    | error     | -     |"
+    );
+}
+
+#[test]
+fn runs_format_as_json() {
+    let runs = serde_json::from_str::<Value>(&format_runs(&test_runs(), OutputFormat::Json)).unwrap();
+
+    assert_eq!(runs[0]["part_one"], "24000");
+    assert_eq!(runs[0]["duration_ms"], 1.5);
+    assert_eq!(runs[1]["part_two"], "##..\n#..#");
+    assert_eq!(runs[2]["part_one"], Value::Null);
+    assert_eq!(runs[2]["error"], "Cargo error: 'cargo build' failed");
+}
+
+// endregion
@@ -0,0 +1,172 @@
+use std::fmt;
+
+use scraper::{Html, Selector};
+
+use crate::error::{AdventemplateError, Result};
+
+/// The title of a day's puzzle, e.g. `Day 6: Tuning Trouble`
+#[derive(Debug, PartialEq, Eq)]
+pub struct DayTitle {
+    pub day_num: u8,
+    pub name: String,
+}
+
+impl DayTitle {
+    /// Find the title in the `<h2>` heading (`--- Day 6: Tuning Trouble ---`) of the puzzle page. The html parser decodes
+    ///  any entities in it (like `&amp;` or `&#39;`) along the way
+    pub fn parse(day_html: &str) -> Result<Self> {
+        let day_document = Html::parse_document(day_html);
+        let heading_selector = Selector::parse("h2").unwrap();
+
+        // The part one heading is the first one that says which day it is (part two's is just '--- Part Two ---')
+        let heading = day_document
+            .select(&heading_selector)
+            .map(|heading| heading.text().collect::<String>())
+            .map(|heading| heading.split_whitespace().collect::<Vec<_>>().join(" "))
+            .map(|heading| heading.trim_matches(|c| c == '-' || c == ' ').to_string())
+            .find(|heading| heading.starts_with("Day "))
+            .ok_or_else(|| AdventemplateError::HtmlParse(String::from("Couldn't find the '--- Day <#>: <Day Title> ---' heading")))?;
+
+        let not_in_format = || {
+            AdventemplateError::HtmlParse(format!(
+                "Title for the day ('{heading}') not in the 'Day <#>: <Day Title>' format we were expecting..."
+            ))
+        };
+        let (day, name) = heading.split_once(':').ok_or_else(not_in_format)?;
+        let day_num = day["Day ".len()..].trim().parse::<u8>().map_err(|_| not_in_format())?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(not_in_format());
+        }
+
+        Ok(DayTitle { day_num, name: name.to_string() })
+    }
+
+    /// The name of the day's project: the day then its title as a slug, e.g. `day6-tuning-trouble`
+    pub fn project_name(&self) -> String {
+        match slugify(&self.name) {
+            slug if slug.is_empty() => format!("day{}", self.day_num),
+            slug => format!("day{}-{slug}", self.day_num),
+        }
+    }
+}
+
+impl fmt::Display for DayTitle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {}: {}", self.day_num, self.name)
+    }
+}
+
+/// Turn a title into something that works in a crate name: lowercase ascii letters and digits, with a single `-` between
+///  the words. Apostrophes are dropped so "Leonardo's" stays one word, accented letters lose their accents, and anything
+///  else that can't go in a crate name splits the words
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::with_capacity(title.len());
+    let mut between_words = false;
+    for c in title.chars() {
+        if matches!(c, '\'' | '\u{2019}') {
+            continue;
+        }
+        match unaccented(c) {
+            Some(letters) => {
+                if between_words && !slug.is_empty() {
+                    slug.push('-');
+                }
+                slug.push_str(&letters.to_ascii_lowercase());
+                between_words = false;
+            }
+            None => between_words = true,
+        }
+    }
+    slug
+}
+
+/// The ascii letters (or digit) a character is written with, if it's a letter or digit at all
+fn unaccented(c: char) -> Option<String> {
+    if c.is_ascii_alphanumeric() {
+        return Some(c.to_string());
+    }
+    let letters = match c.to_lowercase().next()? {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ę' => "e",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' => "i",
+        'ñ' | 'ń' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' => "o",
+        'œ' => "oe",
+        'ś' | 'š' => "s",
+        'ß' => "ss",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(letters.to_string())
+}
+
+// region: Tests
+
+#[cfg(test)]
+fn page_with_heading(heading: &str) -> String {
+    format!("<main><article class=\"day-desc\"><h2>{heading}</h2><p>Something's up with the Elves.</p></article></main>")
+}
+
+#[test]
+fn historical_titles_become_valid_project_names() {
+    for (heading, title, project_name) in [
+        ("--- Day 6: Tuning Trouble ---", "Day 6: Tuning Trouble", "day6-tuning-trouble"),
+        ("--- Day 7: No Space Left On Device ---", "Day 7: No Space Left On Device", "day7-no-space-left-on-device"),
+        ("--- Day 10: Cathode-Ray Tube ---", "Day 10: Cathode-Ray Tube", "day10-cathode-ray-tube"),
+        ("--- Day 2: I Was Told There Would Be No Math ---", "Day 2: I Was Told There Would Be No Math", "day2-i-was-told-there-would-be-no-math"),
+        ("--- Day 10: Elves Look, Elves Say ---", "Day 10: Elves Look, Elves Say", "day10-elves-look-elves-say"),
+        ("--- Day 12: JSAbacusFramework.io ---", "Day 12: JSAbacusFramework.io", "day12-jsabacusframework-io"),
+        ("--- Day 21: RPG Simulator 20XX ---", "Day 21: RPG Simulator 20XX", "day21-rpg-simulator-20xx"),
+        ("--- Day 5: How About a Nice Game of Chess? ---", "Day 5: How About a Nice Game of Chess?", "day5-how-about-a-nice-game-of-chess"),
+        ("--- Day 11: Leonardo&#39;s Monorail ---", "Day 11: Leonardo's Monorail", "day11-leonardos-monorail"),
+        ("--- Day 5: A Maze of Twisty Trampolines, All Alike ---", "Day 5: A Maze of Twisty Trampolines, All Alike", "day5-a-maze-of-twisty-trampolines-all-alike"),
+        ("--- Day 2: 1202 Program Alarm ---", "Day 2: 1202 Program Alarm", "day2-1202-program-alarm"),
+        ("--- Day 18: Many-Worlds Interpretation ---", "Day 18: Many-Worlds Interpretation", "day18-many-worlds-interpretation"),
+        ("--- Day 2: Dive! ---", "Day 2: Dive!", "day2-dive"),
+        ("--- Day 1: Trebuchet?! ---", "Day 1: Trebuchet?!", "day1-trebuchet"),
+        ("--- Day 2: Red-Nosed Reports ---", "Day 2: Red-Nosed Reports", "day2-red-nosed-reports"),
+        ("--- Day 99: Odds &amp; Ends ---", "Day 99: Odds & Ends", "day99-odds-ends"),
+        ("--- Day 99: Santa’s Café ---", "Day 99: Santa’s Café", "day99-santas-cafe"),
+        ("--- Day 99: &lt;Brackets&gt; &amp; &quot;Quotes&quot; ---", "Day 99: <Brackets> & \"Quotes\"", "day99-brackets-quotes"),
+        ("  ---   Day 3:\n  Spread   Out ---  ", "Day 3: Spread Out", "day3-spread-out"),
+        ("--- Day 99: ☃☃☃ ---", "Day 99: ☃☃☃", "day99"),
+    ] {
+        let day_title = DayTitle::parse(&page_with_heading(heading)).unwrap();
+
+        assert_eq!(day_title.to_string(), title);
+        assert_eq!(day_title.project_name(), project_name);
+    }
+}
+
+#[test]
+fn part_two_heading_is_not_the_title() {
+    let day_html = format!(
+        "<h2>--- Part Two ---</h2>{}",
+        page_with_heading("--- Day 14: Regolith Reservoir ---")
+    );
+
+    assert_eq!(
+        DayTitle::parse(&day_html).unwrap(),
+        DayTitle { day_num: 14, name: String::from("Regolith Reservoir") }
+    );
+}
+
+#[test]
+fn pages_without_a_title_are_an_html_parse_error() {
+    for day_html in [
+        String::from("<p>Please log in.</p>"),
+        page_with_heading("--- Part Two ---"),
+        page_with_heading("--- Day six: Tuning Trouble ---"),
+        page_with_heading("--- Day 6 Tuning Trouble ---"),
+        page_with_heading("--- Day 6: ---"),
+    ] {
+        assert!(matches!(DayTitle::parse(&day_html), Err(AdventemplateError::HtmlParse(_))), "{day_html}");
+    }
+}
+
+// endregion
@@ -0,0 +1,105 @@
+use std::{fmt, io, process::ExitCode};
+
+use thiserror::Error;
+
+/// Everything that can go wrong while templating out a day, grouped by where it went wrong
+#[derive(Debug, Error)]
+pub enum AdventemplateError {
+    /// Talking to the Advent of Code site (or whatever the base URL points at) failed
+    #[error("Network error: {0}")]
+    Network(String),
+
+    /// The puzzle page didn't have what we were looking for in it
+    #[error("Couldn't make sense of the puzzle page: {0}")]
+    HtmlParse(String),
+
+    /// Loading or rendering a template failed
+    #[error("Template error: {0}")]
+    Template(#[from] tera::Error),
+
+    /// Running cargo failed, or cargo itself reported a failure
+    #[error("Cargo error: {0}")]
+    Cargo(String),
+
+    /// Reading or writing files failed
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    /// Running git failed, or git refused to do what was asked
+    #[error("Git error: {0}")]
+    Git(String),
+
+    /// Something about the arguments or answers given to adventemplate won't work
+    #[error("Invalid input: {0}")]
+    Input(String),
+}
+
+pub type Result<T> = std::result::Result<T, AdventemplateError>;
+
+/// The broad kinds of errors, which each get their own exit code
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorCategory {
+    Input,
+    Network,
+    HtmlParse,
+    Template,
+    Cargo,
+    Io,
+    Git,
+}
+
+impl AdventemplateError {
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            AdventemplateError::Network(_) => ErrorCategory::Network,
+            AdventemplateError::HtmlParse(_) => ErrorCategory::HtmlParse,
+            AdventemplateError::Template(_) => ErrorCategory::Template,
+            AdventemplateError::Cargo(_) => ErrorCategory::Cargo,
+            AdventemplateError::Io(_) => ErrorCategory::Io,
+            AdventemplateError::Git(_) => ErrorCategory::Git,
+            AdventemplateError::Input(_) => ErrorCategory::Input,
+        }
+    }
+}
+
+impl ErrorCategory {
+    /// The code the process exits with when this kind of error stops it (clap already uses 2 for bad arguments, so start at 3)
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            ErrorCategory::Input => 3,
+            ErrorCategory::Network => 4,
+            ErrorCategory::HtmlParse => 5,
+            ErrorCategory::Template => 6,
+            ErrorCategory::Cargo => 7,
+            ErrorCategory::Io => 8,
+            ErrorCategory::Git => 9,
+        }
+    }
+}
+
+impl From<ErrorCategory> for ExitCode {
+    fn from(category: ErrorCategory) -> Self {
+        ExitCode::from(category.exit_code())
+    }
+}
+
+impl fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ErrorCategory::Input => "input",
+            ErrorCategory::Network => "network",
+            ErrorCategory::HtmlParse => "html parse",
+            ErrorCategory::Template => "template",
+            ErrorCategory::Cargo => "cargo",
+            ErrorCategory::Io => "io",
+            ErrorCategory::Git => "git",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl From<reqwest::Error> for AdventemplateError {
+    fn from(e: reqwest::Error) -> Self {
+        AdventemplateError::Network(e.to_string())
+    }
+}
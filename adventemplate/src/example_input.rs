@@ -0,0 +1,122 @@
+use scraper::{ElementRef, Html, Node, Selector};
+
+/// Find the example inputs given in the puzzle descriptions on the page, the main example first.
+///
+/// An example is a `<pre><code>` block (or the `<code>` leading each item of a `<ul>` list, like the extra examples in day 6)
+///  that directly follows a paragraph introducing an example, e.g. "For example, suppose you receive the following...:".
+///  If nothing on the page looks like that, the first `<pre><code>` block is taken as the example instead.
+pub fn find_examples(day_html: &str) -> Vec<String> {
+    let day_document = Html::parse_document(day_html);
+    let article_selector = Selector::parse("article.day-desc").unwrap();
+
+    let mut examples = Vec::<String>::new();
+    for article in day_document.select(&article_selector) {
+        // Whether the last paragraph we passed introduced the element that follows it as an example
+        let mut introduces_example = false;
+
+        for element in article.children().filter_map(ElementRef::wrap) {
+            match element.value().name() {
+                "p" => {
+                    let paragraph_text = element.text().collect::<String>();
+                    introduces_example =
+                        paragraph_text.to_lowercase().contains("example") && paragraph_text.trim_end().ends_with(':');
+                }
+                "pre" if introduces_example => {
+                    examples.extend(code_text(element));
+                    introduces_example = false;
+                }
+                "ul" if introduces_example => {
+                    examples.extend(
+                        element
+                            .children()
+                            .filter_map(ElementRef::wrap)
+                            .filter_map(leading_code_text),
+                    );
+                    introduces_example = false;
+                }
+                _ => introduces_example = false,
+            }
+        }
+    }
+
+    // Part two often repeats the examples from part one, so only keep the first of each
+    let mut unique_examples = Vec::new();
+    for example in examples {
+        if !unique_examples.contains(&example) {
+            unique_examples.push(example);
+        }
+    }
+
+    if unique_examples.is_empty() {
+        code_blocks(day_html).into_iter().take(1).collect()
+    } else {
+        unique_examples
+    }
+}
+
+/// Every `<pre><code>` block in the puzzle descriptions, in the order they show up on the page
+pub fn code_blocks(day_html: &str) -> Vec<String> {
+    let day_document = Html::parse_document(day_html);
+    let pre_selector = Selector::parse("article.day-desc pre").unwrap();
+
+    day_document.select(&pre_selector).filter_map(code_text).collect()
+}
+
+/// The text of the `<code>` inside a `<pre>` element
+fn code_text(pre_element: ElementRef) -> Option<String> {
+    pre_element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .find(|child| child.value().name() == "code")
+        .map(|code| code.text().collect())
+}
+
+/// The text of the `<code>` element that a list item starts with, if it starts with one
+fn leading_code_text(list_item: ElementRef) -> Option<String> {
+    let first_child = list_item
+        .children()
+        .find(|child| !matches!(child.value(), Node::Text(text) if text.trim().is_empty()))?;
+
+    ElementRef::wrap(first_child)
+        .filter(|first_element| first_element.value().name() == "code")
+        .map(|code| code.text().collect())
+}
+
+// region: Tests
+
+#[test]
+fn finds_the_single_example_for_day_one() {
+    let examples = find_examples(include_str!("../fixtures/day1.html"));
+
+    assert_eq!(
+        examples,
+        ["1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n"]
+    );
+}
+
+#[test]
+fn finds_every_example_for_day_six() {
+    let examples = find_examples(include_str!("../fixtures/day6.html"));
+
+    assert_eq!(
+        examples,
+        [
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            "bvwbjplbgvbhsrlpgdmjqwftvncznppdvjthqldpwncqszvftbrmjlhgnznrnfrfntjfmvfwmzdfjlvtqnbhcprsgzcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
+            "nppdvjthqldpwncqszvftbrmjlhg",
+            "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+            "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
+        ]
+    );
+}
+
+#[test]
+fn falls_back_to_the_first_code_block() {
+    let day_html = "<article class=\"day-desc\"><h2>--- Day 99: Test ---</h2>\
+        <p>Consider this:</p><pre><code>first</code></pre><pre><code>second</code></pre></article>";
+
+    assert_eq!(find_examples(day_html), ["first"]);
+    assert_eq!(code_blocks(day_html), ["first", "second"]);
+}
+
+// endregion
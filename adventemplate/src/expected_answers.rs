@@ -0,0 +1,187 @@
+use scraper::{ElementRef, Html, Selector};
+
+use crate::example_input;
+
+/// The answers to the main example that the puzzle text gives for each part (part two only once it's unlocked)
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// Find the highlighted (`<code><em>` or `<em><code>`) answers to the main example in each part's description.
+///
+/// Answers in running text win over the ones in lists, since the lists are usually going through intermediate steps or extra
+///  examples. When a part only lists answers (like day 6's part two), the one for the main example is picked out of the list.
+pub fn find_expected_answers(day_html: &str) -> ExpectedAnswers {
+    let day_document = Html::parse_document(day_html);
+    let article_selector = Selector::parse("article.day-desc").unwrap();
+    let main_example = example_input::find_examples(day_html).into_iter().next();
+
+    let mut part_answers = day_document
+        .select(&article_selector)
+        .map(|article| find_article_answer(article, main_example.as_deref()));
+
+    ExpectedAnswers {
+        part_one: part_answers.next().flatten(),
+        part_two: part_answers.next().flatten(),
+    }
+}
+
+/// Turn an answer into the Rust literal to compare against: numbers go in as they are and anything else as a string
+pub fn rust_literal(answer: &str) -> String {
+    if answer.parse::<i64>().is_ok() {
+        answer.to_string()
+    } else {
+        format!("{answer:?}")
+    }
+}
+
+/// Fill in the expected answers in the example tests of an already templated main.rs, replacing only the `0` placeholders
+///  the template leaves when it doesn't know an answer so that everything else in the file stays just as it was
+pub fn fill_in_expected_answers(main_rs_contents: &str, expected_answers: &ExpectedAnswers) -> String {
+    let mut main_rs_contents = main_rs_contents.to_string();
+
+    for (part, answer) in [("part_one", &expected_answers.part_one), ("part_two", &expected_answers.part_two)] {
+        let Some(answer) = answer else { continue };
+
+        // Look for the placeholder assertion inside this part's example test
+        let Some(test_start) = main_rs_contents.find(&format!("fn {part}_example_test()")) else { continue };
+        let placeholder = format!("assert_eq!({part}_result, 0);");
+        let Some(placeholder_start) = main_rs_contents[test_start..].find(&placeholder).map(|offset| test_start + offset) else {
+            continue;
+        };
+
+        main_rs_contents.replace_range(
+            placeholder_start..(placeholder_start + placeholder.len()),
+            &format!("assert_eq!({part}_result, {});", rust_literal(answer)),
+        );
+    }
+
+    main_rs_contents
+}
+
+fn find_article_answer(article: ElementRef, main_example: Option<&str>) -> Option<String> {
+    // Find every highlighted value along with the list item it's in (if any)
+    let highlights = article
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .filter_map(|element| highlighted_text(element).map(|text| (text, enclosing_list_item(element))))
+        .collect::<Vec<_>>();
+
+    // The last highlight in running text is most likely the answer
+    let running_text_answer = highlights
+        .iter()
+        .rfind(|(_, list_item)| list_item.is_none())
+        .map(|(text, _)| text.clone());
+
+    // Otherwise, look for the list item about the main example
+    let list_answer = || {
+        highlights
+            .iter()
+            .find(|(_, list_item)| {
+                list_item.is_some_and(|list_item| {
+                    let leading_code = list_item
+                        .children()
+                        .filter_map(ElementRef::wrap)
+                        .next()
+                        .filter(|element| element.value().name() == "code")
+                        .map(|code| code.text().collect::<String>());
+                    leading_code.is_some() && leading_code.as_deref() == main_example
+                })
+            })
+            .map(|(text, _)| text.clone())
+    };
+
+    running_text_answer
+        .or_else(list_answer)
+        .or_else(|| highlights.last().map(|(text, _)| text.clone()))
+}
+
+/// The text of a `<code>` wrapping just an `<em>` or an `<em>` wrapping just a `<code>`
+fn highlighted_text(element: ElementRef) -> Option<String> {
+    let inner_name = match element.value().name() {
+        "code" => "em",
+        "em" => "code",
+        _ => return None,
+    };
+
+    let mut children = element.children();
+    let only_child = children.next().and_then(ElementRef::wrap)?;
+    (children.next().is_none() && only_child.value().name() == inner_name)
+        .then(|| only_child.text().collect::<String>())
+        .filter(|text| !text.trim().is_empty())
+}
+
+fn enclosing_list_item(element: ElementRef) -> Option<ElementRef> {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .find(|ancestor| ancestor.value().name() == "li")
+}
+
+// region: Tests
+
+#[test]
+fn finds_the_part_one_answer_for_day_one() {
+    let expected_answers = find_expected_answers(include_str!("../fixtures/day1.html"));
+
+    assert_eq!(
+        expected_answers,
+        ExpectedAnswers {
+            part_one: Some(String::from("24000")),
+            part_two: None,
+        }
+    );
+}
+
+#[test]
+fn finds_both_answers_for_day_six() {
+    let expected_answers = find_expected_answers(include_str!("../fixtures/day6.html"));
+
+    assert_eq!(
+        expected_answers,
+        ExpectedAnswers {
+            part_one: Some(String::from("7")),
+            part_two: Some(String::from("19")),
+        }
+    );
+}
+
+#[test]
+fn answers_become_rust_literals() {
+    assert_eq!(rust_literal("24000"), "24000");
+    assert_eq!(rust_literal("-3"), "-3");
+    assert_eq!(rust_literal("CMZ"), "\"CMZ\"");
+}
+
+#[test]
+fn filling_in_answers_only_touches_the_placeholders() {
+    let main_rs_contents = "\
+fn part_one_example_test() {
+    let part_one_result = part_one(example);
+    assert_eq!(part_one_result, 7);
+}
+
+fn part_one(input: &str) -> u32 {
+    // Half-written solution
+    0
+}
+
+fn part_two_example_test() {
+    let part_two_result = part_two(example);
+    assert_eq!(part_two_result, 0);
+}
+";
+    let expected_answers = ExpectedAnswers {
+        part_one: Some(String::from("8")),
+        part_two: Some(String::from("19")),
+    };
+
+    assert_eq!(
+        fill_in_expected_answers(main_rs_contents, &expected_answers),
+        main_rs_contents.replace("assert_eq!(part_two_result, 0);", "assert_eq!(part_two_result, 19);")
+    );
+}
+
+// endregion
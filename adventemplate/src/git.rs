@@ -0,0 +1,173 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    command_runner::CommandRunner,
+    error::{AdventemplateError, Result},
+};
+
+/// Run git in the directory, returning what it printed if it succeeded
+fn git(runner: &dyn CommandRunner, repo_dir: &Path, args: &[&str]) -> Result<String> {
+    let output = runner
+        .run("git", args, repo_dir)
+        .map_err(|e| AdventemplateError::Git(format!("Failed to run 'git {}': {e}", args.join(" "))))?;
+    if !output.success {
+        return Err(AdventemplateError::Git(format!(
+            "'git {}' failed ({}): {}",
+            args.join(" "),
+            output.status,
+            output.stderr.trim()
+        )));
+    }
+    Ok(output.stdout)
+}
+
+/// Whether the working tree has no changes in it (staged, unstaged, or untracked files that aren't ignored)
+pub fn is_clean(runner: &dyn CommandRunner, repo_dir: &Path) -> Result<bool> {
+    Ok(git(runner, repo_dir, &["status", "--porcelain"])?.trim().is_empty())
+}
+
+/// The name of the branch that's checked out
+pub fn current_branch(runner: &dyn CommandRunner, repo_dir: &Path) -> Result<String> {
+    Ok(git(runner, repo_dir, &["branch", "--show-current"])?.trim().to_string())
+}
+
+/// Create the branch from wherever HEAD is and switch to it, refusing if there's already a branch by that name
+pub fn create_branch(runner: &dyn CommandRunner, repo_dir: &Path, branch: &str) -> Result<()> {
+    if git(runner, repo_dir, &["rev-parse", "--verify", "--quiet", &format!("refs/heads/{branch}")]).is_ok() {
+        return Err(AdventemplateError::Git(format!("There's already a '{branch}' branch")));
+    }
+    git(runner, repo_dir, &["checkout", "-b", branch])?;
+    Ok(())
+}
+
+/// Stage the project's files (leaving out what its .gitignore rules out), except for any matching the private patterns,
+///  which are relative to the project, e.g. `inputs/question`
+pub fn stage(runner: &dyn CommandRunner, repo_dir: &Path, project_path: &str, private_patterns: &[String]) -> Result<()> {
+    let excludes = private_patterns
+        .iter()
+        .map(|pattern| format!(":(exclude){project_path}/{pattern}"))
+        .collect::<Vec<_>>();
+    let mut args = vec!["add", "--", project_path];
+    args.extend(excludes.iter().map(String::as_str));
+    git(runner, repo_dir, &args)?;
+    Ok(())
+}
+
+/// Every file git is tracking, relative to the root of the repo
+pub fn tracked_files(runner: &dyn CommandRunner, repo_dir: &Path) -> Result<Vec<String>> {
+    Ok(git(runner, repo_dir, &["ls-files", "--full-name", "-z"])?
+        .split('\0')
+        .filter(|tracked_file| !tracked_file.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// The root of the repo the directory is in
+pub fn repo_root(runner: &dyn CommandRunner, repo_dir: &Path) -> Result<PathBuf> {
+    Ok(PathBuf::from(git(runner, repo_dir, &["rev-parse", "--show-toplevel"])?.trim()))
+}
+
+/// Stop tracking the files (given relative to the root of the repo), leaving them where they are
+pub fn untrack(runner: &dyn CommandRunner, repo_dir: &Path, files: &[&str]) -> Result<()> {
+    let mut args = vec!["rm", "--cached", "--quiet", "--"];
+    args.extend(files);
+    git(runner, &repo_root(runner, repo_dir)?, &args)?;
+    Ok(())
+}
+
+/// Commit whatever is staged, returning whether there was anything to commit
+pub fn commit(runner: &dyn CommandRunner, repo_dir: &Path, message: &str) -> Result<bool> {
+    // `git diff --quiet` fails when there are differences, so failing here means there's something staged
+    if git(runner, repo_dir, &["diff", "--cached", "--quiet"]).is_ok() {
+        return Ok(false);
+    }
+    git(runner, repo_dir, &["commit", "--quiet", "-m", message])?;
+    Ok(true)
+}
+
+// region: Tests
+
+#[cfg(test)]
+use crate::command_runner::SystemRunner;
+
+#[cfg(test)]
+fn test_repo() -> tempfile::TempDir {
+    let repo_dir = tempfile::tempdir().unwrap();
+    for args in [
+        ["init", "--quiet", "--initial-branch=main"].as_slice(),
+        &["config", "user.name", "Santa"],
+        &["config", "user.email", "santa@north.pole"],
+        &["config", "commit.gpgsign", "false"],
+        &["commit", "--quiet", "--allow-empty", "-m", "Start"],
+    ] {
+        git(&SystemRunner, repo_dir.path(), args).unwrap();
+    }
+    repo_dir
+}
+
+#[cfg(test)]
+fn write_project(repo_dir: &Path) {
+    let project_path = repo_dir.join("day6-tuning-trouble");
+    std::fs::create_dir_all(project_path.join("inputs")).unwrap();
+    std::fs::create_dir_all(project_path.join("src")).unwrap();
+    std::fs::write(project_path.join(".gitignore"), "/target\n").unwrap();
+    std::fs::write(project_path.join("src").join("main.rs"), "fn main() {}\n").unwrap();
+    std::fs::write(project_path.join("inputs").join("example"), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+    std::fs::write(project_path.join("inputs").join("question"), "my puzzle input\n").unwrap();
+}
+
+#[test]
+fn project_is_committed_on_a_new_branch_without_the_question() {
+    let repo_dir = test_repo();
+    write_project(repo_dir.path());
+    assert!(!is_clean(&SystemRunner, repo_dir.path()).unwrap());
+
+    create_branch(&SystemRunner, repo_dir.path(), "day6").unwrap();
+    stage(&SystemRunner, repo_dir.path(), "day6-tuning-trouble", &[String::from("inputs/question")]).unwrap();
+    assert!(commit(&SystemRunner, repo_dir.path(), "Template out day 6").unwrap());
+
+    assert_eq!(current_branch(&SystemRunner, repo_dir.path()).unwrap(), "day6");
+    assert_eq!(
+        git(&SystemRunner, repo_dir.path(), &["ls-files"]).unwrap(),
+        "day6-tuning-trouble/.gitignore\nday6-tuning-trouble/inputs/example\nday6-tuning-trouble/src/main.rs\n"
+    );
+    assert_eq!(git(&SystemRunner, repo_dir.path(), &["status", "--porcelain"]).unwrap(), "?? day6-tuning-trouble/inputs/question\n");
+
+    // Nothing new to commit the second time around
+    stage(&SystemRunner, repo_dir.path(), "day6-tuning-trouble", &[String::from("inputs/question")]).unwrap();
+    assert!(!commit(&SystemRunner, repo_dir.path(), "Template out day 6 again").unwrap());
+}
+
+#[test]
+fn committed_files_can_be_untracked() {
+    let repo_dir = test_repo();
+    write_project(repo_dir.path());
+    stage(&SystemRunner, repo_dir.path(), "day6-tuning-trouble", &[]).unwrap();
+    commit(&SystemRunner, repo_dir.path(), "Template out day 6").unwrap();
+    assert!(tracked_files(&SystemRunner, repo_dir.path()).unwrap().contains(&String::from("day6-tuning-trouble/inputs/question")));
+
+    // Paths are from the root of the repo, wherever in it this is run from
+    untrack(&SystemRunner, &repo_dir.path().join("day6-tuning-trouble"), &["day6-tuning-trouble/inputs/question"]).unwrap();
+
+    assert!(!tracked_files(&SystemRunner, repo_dir.path()).unwrap().contains(&String::from("day6-tuning-trouble/inputs/question")));
+    assert!(repo_dir.path().join("day6-tuning-trouble").join("inputs").join("question").exists());
+}
+
+#[test]
+fn existing_branches_are_not_reused() {
+    let repo_dir = test_repo();
+    git(&SystemRunner, repo_dir.path(), &["branch", "day6"]).unwrap();
+
+    assert!(is_clean(&SystemRunner, repo_dir.path()).unwrap());
+    assert!(matches!(create_branch(&SystemRunner, repo_dir.path(), "day6"), Err(AdventemplateError::Git(_))));
+    assert_eq!(current_branch(&SystemRunner, repo_dir.path()).unwrap(), "main");
+}
+
+#[test]
+fn outside_a_repo_is_a_git_error() {
+    let not_a_repo = tempfile::tempdir().unwrap();
+
+    assert!(matches!(is_clean(&SystemRunner, not_a_repo.path()), Err(AdventemplateError::Git(_))));
+}
+
+// endregion
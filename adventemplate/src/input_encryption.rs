@@ -0,0 +1,160 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+use crate::error::{AdventemplateError, Result};
+
+/// What goes on the end of an input's file name for the encrypted copy of it, e.g. `inputs/question.enc`
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+/// How many bytes of nonce go in front of the encrypted input
+const NONCE_LEN: usize = 12;
+
+/// Where the key is kept when no key file is given: next to the session file, well away from the repo
+pub fn default_key_file() -> Option<PathBuf> {
+    dirs::config_dir().map(|config_dir| config_dir.join("adventemplate").join("input.key"))
+}
+
+/// The path of the encrypted copy of an input
+pub fn encrypted_path(input_path: &Path) -> PathBuf {
+    let mut file_name = input_path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".{ENCRYPTED_EXTENSION}"));
+    input_path.with_file_name(file_name)
+}
+
+/// The key used to encrypt and decrypt the inputs, kept as hex in a file
+pub struct InputKey(Key);
+
+impl InputKey {
+    /// Read the key from the file
+    pub fn load(key_path: &Path) -> Result<Self> {
+        let key_hex = fs::read_to_string(key_path).map_err(|e| {
+            AdventemplateError::Input(format!("Couldn't read the input key from '{}': {e}", key_path.display()))
+        })?;
+        let key_bytes = from_hex(key_hex.trim())
+            .filter(|key_bytes| key_bytes.len() == 32)
+            .ok_or_else(|| AdventemplateError::Input(format!("'{}' doesn't hold a 32 byte key in hex", key_path.display())))?;
+        Ok(InputKey(*Key::from_slice(&key_bytes)))
+    }
+
+    /// Read the key from the file, making a new one there first if there isn't a file yet
+    pub fn load_or_create(key_path: &Path) -> Result<Self> {
+        if key_path.exists() {
+            return InputKey::load(key_path);
+        }
+
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        if let Some(key_dir) = key_path.parent() {
+            fs::create_dir_all(key_dir)?;
+        }
+        fs::write(key_path, format!("{}\n", to_hex(&key)))?;
+        // Only the user gets to read it
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(key_path, fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(InputKey(key))
+    }
+
+    /// Encrypt the input, with a fresh nonce in front of it
+    pub fn encrypt(&self, input: &[u8]) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let encrypted = ChaCha20Poly1305::new(&self.0)
+            .encrypt(&nonce, input)
+            .expect("encrypting in memory doesn't fail");
+        [nonce.as_slice(), &encrypted].concat()
+    }
+
+    /// Decrypt an input encrypted with `encrypt`, which fails if it was encrypted with a different key or has been changed
+    pub fn decrypt(&self, encrypted: &[u8]) -> Result<Vec<u8>> {
+        let wrong_key = || AdventemplateError::Input(String::from("Couldn't decrypt the input (was it encrypted with a different key?)"));
+        if encrypted.len() < NONCE_LEN {
+            return Err(wrong_key());
+        }
+        let (nonce, encrypted) = encrypted.split_at(NONCE_LEN);
+        ChaCha20Poly1305::new(&self.0)
+            .decrypt(Nonce::from_slice(nonce), encrypted)
+            .map_err(|_| wrong_key())
+    }
+}
+
+/// Write the encrypted copy of the input next to it, returning whether it needed (re)writing
+pub fn encrypt_input(key: &InputKey, input_path: &Path) -> Result<bool> {
+    let input = fs::read(input_path)?;
+    let encrypted_path = encrypted_path(input_path);
+
+    // A fresh nonce makes every encryption different, so only write it out when the input actually changed
+    match fs::read(&encrypted_path) {
+        Ok(existing) if key.decrypt(&existing).is_ok_and(|decrypted| decrypted == input) => return Ok(false),
+        Ok(_) => {}
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+    fs::write(&encrypted_path, key.encrypt(&input))?;
+    Ok(true)
+}
+
+/// Decrypt the encrypted copy of the input back into the input
+pub fn decrypt_input(key: &InputKey, input_path: &Path) -> Result<()> {
+    let decrypted = key.decrypt(&fs::read(encrypted_path(input_path))?)?;
+    fs::write(input_path, decrypted)?;
+    Ok(())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..(i + 2)], 16).ok())
+        .collect()
+}
+
+// region: Tests
+
+#[test]
+fn inputs_round_trip_through_encryption() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let key_path = temp_dir.path().join("keys").join("input.key");
+    let question_path = temp_dir.path().join("question");
+    fs::write(&question_path, "1000\n2000\n3000\n").unwrap();
+
+    let key = InputKey::load_or_create(&key_path).unwrap();
+    assert!(encrypt_input(&key, &question_path).unwrap());
+    assert!(!encrypt_input(&key, &question_path).unwrap());
+    let encrypted = fs::read(temp_dir.path().join("question.enc")).unwrap();
+    assert!(!String::from_utf8_lossy(&encrypted).contains("2000"));
+
+    // The same key is read back from its file to decrypt with
+    fs::remove_file(&question_path).unwrap();
+    decrypt_input(&InputKey::load_or_create(&key_path).unwrap(), &question_path).unwrap();
+    assert_eq!(fs::read_to_string(&question_path).unwrap(), "1000\n2000\n3000\n");
+}
+
+#[test]
+fn the_wrong_key_is_an_input_error() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let key = InputKey::load_or_create(&temp_dir.path().join("input.key")).unwrap();
+    let other_key = InputKey::load_or_create(&temp_dir.path().join("other.key")).unwrap();
+    let encrypted = key.encrypt(b"1000\n2000\n3000\n");
+
+    assert!(matches!(other_key.decrypt(&encrypted), Err(AdventemplateError::Input(_))));
+    assert!(matches!(key.decrypt(&encrypted[..5]), Err(AdventemplateError::Input(_))));
+
+    fs::write(temp_dir.path().join("bad.key"), "not hex\n").unwrap();
+    assert!(matches!(InputKey::load(&temp_dir.path().join("bad.key")), Err(AdventemplateError::Input(_))));
+}
+
+// endregion
@@ -0,0 +1,196 @@
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    io::{self, BufRead, IsTerminal, Write},
+};
+
+use colored::Colorize;
+use scraper::{Html, Selector};
+
+/// Words that can't be used as identifiers in Rust (strict and reserved keywords)
+const RUST_KEYWORDS: [&str; 51] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static",
+    "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do",
+    "final", "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "try",
+];
+
+/// Words that start a noun phrase in the puzzle text, after which the name of the thing usually follows
+const DETERMINERS: [&str; 7] = ["a", "an", "the", "your", "this", "these", "each"];
+
+/// Words that end a noun phrase, or just aren't interesting enough to name an input after
+const STOP_WORDS: [&str; 60] = [
+    "a", "an", "the", "your", "this", "these", "each", "of", "in", "on", "at", "to", "for", "from", "by", "with", "and",
+    "or", "but", "is", "are", "was", "were", "be", "been", "that", "which", "who", "it", "its", "they", "them", "their",
+    "you", "you're", "you'd", "can", "could", "should", "would", "will", "into", "as", "so", "if", "then", "than", "up",
+    "down", "all", "any", "some", "one", "two", "how", "what", "many", "much", "also", "there",
+];
+
+/// Check that the name can be dropped into the template as a Rust identifier (`question_<name>`, `parse_<name>`, and the name by itself)
+pub fn validate(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    match chars.next() {
+        None => return Err(String::from("The name can't be empty")),
+        Some(first) if !(first.is_ascii_alphabetic() || first == '_') => {
+            return Err(format!("'{name}' needs to start with a letter or an underscore"))
+        }
+        _ => {}
+    }
+    if !chars.all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(format!("'{name}' can only have letters, numbers, and underscores in it"));
+    }
+    if name == "_" || RUST_KEYWORDS.contains(&name) {
+        return Err(format!("'{name}' is a Rust keyword"));
+    }
+    Ok(())
+}
+
+/// Turn a phrase like "Sensor data" into snake_case, e.g. "sensor_data"
+pub fn to_snake_case(phrase: &str) -> String {
+    phrase
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Suggest names for the puzzle input from the first part's description, best guesses first.
+///
+/// The puzzle text usually names the input right before saying "(your puzzle input)", so the noun phrases there come first
+///  (the ones mentioned most often in the description leading), followed by the words that come up the most overall.
+pub fn suggest_names(day_html: &str) -> Vec<String> {
+    let day_document = Html::parse_document(day_html);
+    let article_selector = Selector::parse("article.day-desc").unwrap();
+    let Some(article) = day_document.select(&article_selector).next() else {
+        return Vec::new();
+    };
+    let description = article.text().collect::<String>();
+    let lowercase_description = description.to_lowercase();
+
+    // Noun phrases in the text leading up to each mention of the puzzle input
+    let mut phrases = Vec::new();
+    let split_at_mentions = description.split("your puzzle input").collect::<Vec<_>>();
+    for before_mention in &split_at_mentions[..(split_at_mentions.len() - 1)] {
+        let sentence = before_mention.rsplit(['.', '!', '?', ':', ';']).next().unwrap_or_default();
+        let words = sentence.split_whitespace().map(trim_punctuation).collect::<Vec<_>>();
+
+        for (i, word) in words.iter().enumerate().rev() {
+            if !DETERMINERS.contains(&word.to_lowercase().as_str()) {
+                continue;
+            }
+            let phrase = words[(i + 1)..]
+                .iter()
+                .take_while(|word| !word.is_empty() && !STOP_WORDS.contains(&word.to_lowercase().as_str()))
+                .take(2)
+                .copied()
+                .collect::<Vec<_>>()
+                .join(" ");
+            if !phrase.is_empty() {
+                phrases.push(phrase.to_lowercase());
+            }
+        }
+    }
+    phrases.sort_by_key(|phrase| Reverse(lowercase_description.matches(phrase.as_str()).count()));
+    let mut suggestions = phrases.iter().map(|phrase| to_snake_case(phrase)).collect::<Vec<_>>();
+
+    // The most common interesting words in the description
+    let mut word_counts = HashMap::<String, usize>::new();
+    for word in description.split_whitespace().map(trim_punctuation) {
+        let word = word.to_lowercase();
+        if word.len() >= 4 && word.chars().all(|c| c.is_ascii_alphabetic()) && !STOP_WORDS.contains(&word.as_str()) {
+            *word_counts.entry(word).or_default() += 1;
+        }
+    }
+    let mut common_words = word_counts.into_iter().filter(|(_, count)| *count > 1).collect::<Vec<_>>();
+    common_words.sort_by(|(word_a, count_a), (word_b, count_b)| count_b.cmp(count_a).then(word_a.cmp(word_b)));
+    suggestions.extend(common_words.into_iter().take(3).map(|(word, _)| word));
+
+    // Only keep the first of each suggestion that would actually work as a name
+    let mut unique_suggestions = Vec::new();
+    for suggestion in suggestions {
+        if validate(&suggestion).is_ok() && !unique_suggestions.contains(&suggestion) {
+            unique_suggestions.push(suggestion);
+        }
+    }
+    unique_suggestions.truncate(5);
+    unique_suggestions
+}
+
+/// Ask for the name of the input structure, offering the suggestions from the puzzle text and checking that whatever is
+///  picked works as a Rust identifier
+pub fn prompt_for_name(day_html: &str) -> io::Result<String> {
+    if !io::stdin().is_terminal() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Missing input_structure_name and not running in a terminal to ask for one",
+        ));
+    }
+
+    let suggestions = suggest_names(day_html);
+    println!("\n{}", "What should the puzzle input be called in main.rs?".bold());
+    for (i, suggestion) in suggestions.iter().enumerate() {
+        println!("  {}) {suggestion}", i + 1);
+    }
+
+    loop {
+        match suggestions.first() {
+            Some(first_suggestion) => print!("  Pick a number or type a name [{first_suggestion}]: "),
+            None => print!("  Type a name: "),
+        }
+        io::stdout().flush()?;
+
+        let mut answer = String::new();
+        if io::stdin().lock().read_line(&mut answer)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "No name given for the input structure"));
+        }
+        let answer = answer.trim();
+
+        // An empty answer takes the first suggestion and a number picks one from the list
+        let name = match answer.parse::<usize>() {
+            _ if answer.is_empty() => suggestions.first().cloned().unwrap_or_default(),
+            Ok(number) if (1..=suggestions.len()).contains(&number) => suggestions[number - 1].clone(),
+            _ => answer.to_string(),
+        };
+
+        match validate(&name) {
+            Ok(()) => return Ok(name),
+            Err(reason) => println!("{}", format!("  {reason}, try another one").yellow()),
+        }
+    }
+}
+
+fn trim_punctuation(word: &str) -> &str {
+    word.trim_matches(|c: char| !c.is_ascii_alphanumeric() && c != '\'')
+}
+
+// region: Tests
+
+#[test]
+fn validates_rust_identifiers() {
+    assert_eq!(validate("heightmap"), Ok(()));
+    assert_eq!(validate("sensor_data"), Ok(()));
+    assert_eq!(validate("_private2"), Ok(()));
+    assert!(validate("").is_err());
+    assert!(validate("2d_grid").is_err());
+    assert!(validate("sensor data").is_err());
+    assert!(validate("sensor-data").is_err());
+    assert!(validate("type").is_err());
+    assert!(validate("_").is_err());
+}
+
+#[test]
+fn phrases_become_snake_case() {
+    assert_eq!(to_snake_case("Sensor data"), "sensor_data");
+    assert_eq!(to_snake_case("  rope  motions! "), "rope_motions");
+}
+
+#[test]
+fn suggests_the_heightmap_for_day_twelve() {
+    let suggestions = suggest_names(include_str!("../fixtures/day12.html"));
+
+    assert_eq!(suggestions[..3], ["heightmap", "device", "surrounding_area"]);
+    assert!(suggestions.iter().all(|suggestion| validate(suggestion).is_ok()));
+}
+
+// endregion
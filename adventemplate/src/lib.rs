@@ -0,0 +1,27 @@
+//! The workings of adventemplate, with the command line on top of them in `main.rs`. Setting up a day goes through a
+//!  [`Scaffolder`](scaffolder::Scaffolder), which can be pointed at any root directory and given stand-ins for the site and
+//!  for the programs it runs (cargo and git)
+
+pub mod answers;
+pub mod aoc_client;
+pub mod command_runner;
+pub mod config;
+pub mod day_runs;
+pub mod day_title;
+pub mod error;
+pub mod example_input;
+pub mod expected_answers;
+pub mod git;
+#[cfg(feature = "encryption")]
+pub mod input_encryption;
+pub mod input_structure_name;
+pub mod main_rs_regeneration;
+pub mod markdown;
+pub mod privacy;
+pub mod puzzle_page;
+pub mod scaffolder;
+pub mod submission;
+pub mod templates;
+pub mod workspace;
+#[cfg(test)]
+mod test_server;
@@ -1,358 +1,531 @@
 use std::{
-    process::Command,
-    path::Path,
-    fs::{File, self},
-    io,
+    env,
+    process::ExitCode,
+    path::{Path, PathBuf},
 };
 
-use clap::Parser;
-use colored::{Colorize, ColoredString};
-use tera::{Tera, Context};
+use clap::{Parser, Subcommand};
+use colored::Colorize;
+
+use adventemplate::{
+    answers::{self, AnswerBook, Verdict},
+    aoc_client::{self, AocClient},
+    command_runner::SystemRunner,
+    config::{Config, InputFiles},
+    day_runs::{self, OutputFormat},
+    error::{self, AdventemplateError, ErrorCategory},
+    git,
+    input_structure_name,
+    main_rs_regeneration::ConflictChoice,
+    privacy,
+    puzzle_page::PuzzleSource,
+    scaffolder::{self, get_file_bg_string, DayInfo, Scaffolder, StepResult, NAMED_STEP_NAMES, OPTIONAL_STEP_NAMES, STEP_NAMES},
+    submission::{self, Outcome, SubmissionHistory},
+    templates::{self, TemplateSet},
+    workspace::{self, ProjectLayout},
+};
+#[cfg(feature = "encryption")]
+use adventemplate::{
+    input_encryption,
+    scaffolder::{has_content, input_key_path, print_already_exists},
+};
 
 // Define program input with clap
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Adventemplinput {
-    #[arg(help = "The number of the day of Advent of Code it is.")]
-    day_num: u8,
+    #[command(subcommand)]
+    command: Option<AdventemplateCommand>,
+
+    #[arg(required = true, help = "The number of the day of Advent of Code it is.")]
+    day_num: Option<u8>,
 
-    #[arg(help = "The terminology used to describe the input for the question used in templating the main.rs file.")]
+    #[arg(help = "The terminology used to describe the input for the question used in templating the main.rs file. Asked for (with suggestions from the puzzle text) if left out.")]
     input_structure_name: Option<String>,
+
+    #[arg(long, global = true, help = "The year of Advent of Code to template a day from. [default: 2022, or the year in adventemplate.toml]")]
+    year: Option<u16>,
+
+    #[arg(long, value_enum, help = "Where day projects go: right in the current directory (flat) or in a folder for the year. [default: flat, or the layout in adventemplate.toml]")]
+    layout: Option<ProjectLayout>,
+
+    #[arg(long, env = "ADVENTEMPLATE_TEMPLATE_DIR", help = "A directory of template flavours (one folder of Tera files per flavour) to use over the built-in ones.")]
+    template_dir: Option<PathBuf>,
+
+    #[arg(long, default_value = templates::DEFAULT_FLAVOUR, help = "The flavour of templates to render: default, grid, parser-heavy, simulation, or one from the template directory.")]
+    flavour: String,
+
+    #[arg(long, help = "Only read puzzle pages from the local cache instead of fetching them.")]
+    offline: bool,
+
+    #[arg(long, env = "ADVENTEMPLATE_CACHE_DIR", help = "The directory fetched puzzle pages are cached in.")]
+    cache_dir: Option<PathBuf>,
+
+    #[arg(long, global = true, env = "ADVENTEMPLATE_BASE_URL", default_value = aoc_client::DEFAULT_BASE_URL, help = "The site to fetch puzzle pages and inputs from.")]
+    base_url: String,
+
+    #[arg(long, global = true, env = "ADVENTEMPLATE_SESSION_FILE", help = "A file holding the session token used to download puzzle inputs, checked when AOC_SESSION isn't set.")]
+    session_file: Option<PathBuf>,
+
+    #[arg(long, global = true, env = "ADVENTEMPLATE_KEY_FILE", help = "The file holding the key puzzle inputs are encrypted with (made by the encrypt step if it isn't there yet).")]
+    key_file: Option<PathBuf>,
+
+    #[arg(long, help = "Use this code block on the puzzle page (1 for the first) as the example input instead of guessing which blocks are examples.")]
+    example_index: Option<usize>,
+
+    #[arg(long, value_delimiter = ',', value_parser = STEP_NAMES, conflicts_with = "skip", help = "Only run these setup steps (comma separated).")]
+    only: Vec<String>,
+
+    #[arg(long, value_delimiter = ',', value_parser = STEP_NAMES, help = "Run every setup step except these (comma separated).")]
+    skip: Vec<String>,
+
+    #[arg(long, value_delimiter = ',', value_parser = OPTIONAL_STEP_NAMES, help = "Also run these optional setup steps (comma separated).")]
+    with: Vec<String>,
+
+    #[arg(long, help = "Print the setup steps that would run and the files they would create or overwrite, without writing anything.")]
+    dry_run: bool,
+
+    #[arg(long, help = "Render main.rs from the template again even if it was already templated and has been edited since.")]
+    regenerate_main: bool,
+
+    #[arg(long, value_enum, help = "What to do when main.rs has edits that rendering the template would throw away, instead of asking.")]
+    on_conflict: Option<ConflictChoice>,
 }
 
-fn main() {
-    // Joy to the world!
-    println!(
-        "\nHello and {} {} {} {}{}! Let's template out some Advent of Code boilerplate solutions!",
-        "joy".bright_yellow().bold(),
-        "to".red(),
-        "the".green(),
-        "wor".red(), "ld".green(),
-    );
+#[derive(Subcommand)]
+enum AdventemplateCommand {
+    /// Submit an answer for a day that's been templated, running the day's solution to get it if it isn't given
+    Submit {
+        #[arg(help = "The number of the day to submit an answer for.")]
+        day_num: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2), help = "Which part the answer is for (1 or 2).")]
+        part: u8,
+
+        #[arg(help = "The answer to submit. Taken from the 'Part One:'/'Part Two:' output of the day's solution if left out.")]
+        answer: Option<String>,
+    },
+    /// Build and run the days' projects in release mode, printing a table of their answers and how long they took
+    Run {
+        #[arg(help = "The days to run. Every day's project that can be found is run if none are given.")]
+        days: Vec<u8>,
+
+        #[arg(long, value_enum, default_value_t, help = "How to print the results.")]
+        format: OutputFormat,
+    },
+    /// Run the days' projects and check their answers against the ones recorded in answers.toml
+    Verify {
+        #[arg(help = "The days to verify. Every day's project that can be found is verified if none are given.")]
+        days: Vec<u8>,
+
+        #[arg(long, help = "Record the answers of parts that don't have one in answers.toml yet.")]
+        record: bool,
+    },
+    /// Look for puzzle inputs committed to the repo, which aren't meant to be shared
+    Scrub {
+        #[arg(long, help = "Stop tracking the inputs that were found (leaving the files where they are) and add a gitignore rule for them to their projects.")]
+        untrack: bool,
+    },
+    /// Decrypt the days' encrypted puzzle inputs (from the encrypt step) so the projects can build, e.g. after cloning
+    Decrypt {
+        #[arg(help = "The days to decrypt the inputs of. Every day's project that can be found is decrypted if none are given.")]
+        days: Vec<u8>,
+    },
+}
+
+/// The year templated when neither --year nor the config file give one
+const DEFAULT_YEAR: u16 = 2022;
 
+fn main() -> ExitCode {
     // * Parse input arguments with clap
-    // Steps we can choose to do or not:
+    // Steps we can choose to do or not (all by default, or a whitelist with --only or a blacklist with --skip):
     //  - cargo new (needs an input file)
     //  - template main file
     //  - add inputs dir
+    //  - extract example inputs
     //  - add gitignore
     //  - add README
-    // Possible ways to configure what steps run - all, whitelist, blacklist
 
     // Parse with clap
-    let adventemplinput = Adventemplinput::parse();
-
-    // TODO: prompt for input_structure_name if it is not included
-    // ? Perhaps we can provide them with the text block of the article description if they want to be able to read it there
-    let input_structure_name = adventemplinput.input_structure_name.expect("Missing input_structure_name. For now, that's kinda required");
-    // let input_structure_name = String::from("datastream_buffer");j
-
-    // TODO: given the day, request and parse the page for the day and get the title & project name
-    let year = "2022";
-    let day_num = adventemplinput.day_num;
-    println!("\nLooking up info for day {day_num}...");
-    let day_info = get_day_title_and_project_name(year, day_num, input_structure_name);
+    let mut adventemplinput = Adventemplinput::parse();
+
+    // Fill in the defaults from the config file (the command line still gets the last word)
+    let config = match env::current_dir()
+        .map_err(AdventemplateError::from)
+        .and_then(|current_dir| Config::find(&current_dir, dirs::config_dir().as_deref()))
+    {
+        Ok((config, _)) => config,
+        Err(e) => {
+            eprintln!("{}", format!("Couldn't read the config: '{e}'").red());
+            return e.category().into();
+        }
+    };
+    let year = &adventemplinput.year.or(config.year).unwrap_or(DEFAULT_YEAR).to_string();
+
+    // * Running the days prints just the results, so they can be piped or pasted somewhere
+    if let Some(AdventemplateCommand::Run { days, format }) = &adventemplinput.command {
+        return run_days(year, days, *format);
+    }
+    if let Some(AdventemplateCommand::Verify { days, record }) = &adventemplinput.command {
+        return verify_days(year, days, *record);
+    }
+    if let Some(AdventemplateCommand::Scrub { untrack }) = &adventemplinput.command {
+        return scrub_inputs(&config.inputs, *untrack);
+    }
+    if let Some(AdventemplateCommand::Decrypt { days }) = &adventemplinput.command {
+        return decrypt_inputs(year, days, &config.inputs, adventemplinput.key_file.as_deref());
+    }
+
+    // Joy to the world!
     println!(
-        "  Title found for day {day_num}:\n    {}",
-        day_info.day_title.bold()
-        // day_info.day_title.bold().on_truecolor(80, 40, 90).truecolor(150, 140, 10)
+        "\nHello and {} {} {} {}{}! Let's template out some Advent of Code boilerplate solutions!",
+        "joy".bright_yellow().bold(),
+        "to".red(),
+        "the".green(),
+        "wor".red(), "ld".green(),
     );
 
-    // Definte the possible steps
-    let template_steps = [
-        SetupStep {
-            step_message: String::from("Creating new rust project with cargo..."),
-            step_executor: create_rust_project,
-            critical: true,
-        },
-        SetupStep {
-            step_message: String::from("Adding gitignore to project..."),
-            step_executor: add_gitignore,
-            critical: false,
-        },
-        SetupStep {
-            step_message: String::from("Creating README for project..."),
-            step_executor: create_readme,
-            critical: false,
-        },
-        SetupStep {
-            step_message: String::from("Creating inputs folder..."),
-            step_executor: create_inputs,
-            critical: false,
-        },
-        SetupStep {
-            step_message: String::from("Templating main.rs file..."),
-            step_executor: template_main_rs,
-            critical: false,
-        },
-    ];
-
-    // We could filter the template_steps based on the input...
+    let session_file = adventemplinput.session_file.or_else(aoc_client::default_session_file);
 
-    // * Execute the steps specified by the input arguments
-    for step in template_steps {
-        println!("\n--------------------");
-        println!("\n{}", step.step_message.blue());
-        // ? Maybe make all text outputted from the step execution indented
-        match (step.step_executor)(&day_info) {
-            Ok(()) => println!("{}", "Success!".green()),
+    // * Answer submission is its own thing, separate from the setup steps
+    if let Some(AdventemplateCommand::Submit { day_num, part, answer }) = adventemplinput.command.take() {
+        let client = AocClient::new(adventemplinput.base_url, aoc_client::read_session_token(session_file.as_deref()));
+        return match submit_answer(&client, year, day_num, part, answer) {
+            Ok(Outcome::Correct) => ExitCode::SUCCESS,
+            Ok(_) => ExitCode::FAILURE,
             Err(e) => {
-                println!("{}", format!("Failed with error: '{e}'").red());
-                if step.critical {
-                    // Just error out for now
-                    panic!("{}", "\nThere was an error in a critical step. Stopping execution.".red())
-                }
-            },
-        }
+                println!("{}", format!("Couldn't submit the answer: '{e}'").red());
+                e.category().into()
+            }
+        };
     }
-}
 
-struct DayInfo {
-    project_name: String,
-    day_title: String,
-    day_url: String,
-    input_structure_name: String,
-}
-
-struct SetupStep {
-    step_message: String,
-    step_executor: fn(&DayInfo) -> io::Result<()>,
-    critical: bool,
-}
-
-// Maybe split these up if we want to add a test case
-// fn lookup_day_info(year: &str, day_num: i32) -> (String, String, String) {
-
-// }
+    // Check the input_structure_name up front if it was given (it gets prompted for once the puzzle page is in hand otherwise)
+    if let Some(input_structure_name) = &adventemplinput.input_structure_name {
+        if let Err(reason) = input_structure_name::validate(input_structure_name) {
+            let e = AdventemplateError::Input(format!("Invalid input_structure_name: {reason}"));
+            println!("{}", e.to_string().red());
+            return e.category().into();
+        }
+    }
 
-// fn get_day_title(year: &str, day_num: u8) -> String {
-//     // 
-// }
+    // Load the templates up front so a missing flavour or a broken template doesn't leave a half set up project
+    let template_dir = adventemplinput.template_dir.or(config.template_dir);
+    let templates = match TemplateSet::load(template_dir.as_deref(), &adventemplinput.flavour) {
+        Ok(templates) => templates,
+        Err(e) => {
+            println!("{}", format!("Couldn't load the templates: '{e}'").red());
+            return e.category().into();
+        }
+    };
 
-fn get_day_title_and_project_name(year: &str, day_num: u8, input_structure_name: String) -> DayInfo {
-    // Determine the link for the day
-    let day_url = format!("https://adventofcode.com/{year}/day/{day_num}");
+    // Only keep the steps that were asked for (the config file can change which steps run by default)
+    let template_steps = match scaffolder::select_steps(&adventemplinput.only, &adventemplinput.skip, &adventemplinput.with, config.steps.as_deref()) {
+        Ok(template_steps) => template_steps,
+        Err(e) => {
+            println!("{}", e.to_string().red());
+            return e.category().into();
+        }
+    };
 
-    // Get the title of this day's question from the html response from day_url
-    let day_html = reqwest::blocking::get(&day_url)
-        .expect("Failed to get response for the page from this day's question...")
-        .text()
-        .expect("Failed to get the text from the page from this day's question");
-    let title = {
-        let start_title = day_html.find("--- ").expect("Couldn't find beginning of title") + 4;
-        let end_title = day_html.find(" ---").expect("Couldn't find end of title");
-        &day_html[start_title..end_title]
+    // Everything is set up in the current directory, fetching from the site (or the cache) and running the real cargo & git
+    let puzzle_source = PuzzleSource {
+        client: AocClient::new(adventemplinput.base_url, aoc_client::read_session_token(session_file.as_deref())),
+        cache_dir: adventemplinput.cache_dir.unwrap_or_else(PuzzleSource::default_cache_dir),
+        offline: adventemplinput.offline,
+        read_only: adventemplinput.dry_run,
     };
-    let day_title = format!("Advent of Code - {title}");
+    let scaffolder = Scaffolder::new(".", puzzle_source);
+
+    // Committing the project on its own branch would sweep up (or trip over) any other changes, so those have to be dealt
+    //  with first
+    let commits = template_steps.iter().any(|step| step.name == "git");
+    if commits && !adventemplinput.dry_run {
+        if let Err(e) = scaffolder.ensure_clean() {
+            println!("{}", format!("Not setting anything up: '{e}'").red());
+            return e.category().into();
+        }
+    }
 
-    // let h2_content = String::from("--- Day 6: Tuning Trouble ---");
-    // let day_title = format!(
-    //     "Advent of Code - {}",
-    //     (&h2_content).replace("---", "").trim()
-    // );
+    // Only ask for the input_structure_name when one of the steps is going to use it
+    let needs_input_structure_name = template_steps.iter().any(|step| NAMED_STEP_NAMES.contains(&step.name));
 
-    // Get the project name from the day_title
-    let project_name = format!(
-        "day{}-{}",
+    // Given the day, request and parse the page for the day and get the title & project name
+    let day_num = adventemplinput.day_num.expect("clap requires the day number without a subcommand");
+    println!("\nLooking up info for day {day_num}{}...", if scaffolder.puzzle_source.offline { " (offline)" } else { "" });
+    let day_info = match scaffolder.look_up_day(
+        year,
         day_num,
-        day_title
-            .split_once(": ")
-            .expect("Title for the day not in the 'Day <#>: <Day Title>' format we were expecting...")
-            .1
-            .split(' ')
-            .map(|title_word| title_word.to_lowercase())
-            .collect::<Vec<String>>()
-            .join("-")
+        adventemplinput.layout.or(config.layout).unwrap_or_default(),
+        adventemplinput.input_structure_name,
+        needs_input_structure_name,
+    ) {
+        Ok(day_info) => DayInfo {
+            example_index: adventemplinput.example_index,
+            regenerate_main: adventemplinput.regenerate_main,
+            on_conflict: adventemplinput.on_conflict,
+            templates,
+            input_files: config.inputs.clone(),
+            gitignore: config.gitignore.clone(),
+            private_files: config.private_files.clone(),
+            key_file: adventemplinput.key_file,
+            ..day_info
+        },
+        Err(e) => {
+            // Without the info for the day there's nothing any of the steps can do
+            println!("{}", format!("  Couldn't look up the day: '{e}'").red());
+            return e.category().into();
+        }
+    };
+    println!(
+        "  Title found for day {day_num}:\n    {}",
+        day_info.day_title.bold()
+        // day_info.day_title.bold().on_truecolor(80, 40, 90).truecolor(150, 140, 10)
     );
 
-    // Return the info for the day
-    DayInfo {
-        project_name,
-        day_title,
-        day_url,
-        input_structure_name,
+    // * For a dry run, just lay out what each step would do
+    if adventemplinput.dry_run {
+        println!("\n{}", "Dry run, nothing will be written. Here's the plan:".bold());
+        scaffolder.plan(&day_info, template_steps);
+        if config.git_add {
+            println!("\nWould stage {} with 'git add'", get_file_bg_string(&scaffolder.project_dir(&day_info)));
+        }
+        return ExitCode::SUCCESS;
     }
-}
 
-fn get_file_bg_string(file_path: &Path) -> ColoredString {
-    file_path.to_str().unwrap().on_truecolor(60, 70, 80)
-}
+    // * Execute the steps specified by the input arguments
+    let step_results = scaffolder.run(&day_info, template_steps);
 
-fn print_already_exists(file_path: &Path) {
-    println!(
-        "{}",
-        format!("  {} already exists, skipping...", get_file_bg_string(file_path)).yellow()
-    );
-}
+    // * Stage what was created, if the config asks for it
+    if config.git_add && scaffolder.project_dir(&day_info).is_dir() {
+        scaffolder.stage_project(&day_info);
+    }
 
-fn print_created(file_path: &Path) {
-    println!(
-        "{}",
-        format!("  {} created!", get_file_bg_string(file_path)).green()
-    );
+    // * Sum up how it went, exiting with the code for the first failure (if there was one)
+    print_summary(&step_results);
+    step_results
+        .iter()
+        .find_map(|(_, step_result)| match step_result {
+            StepResult::Failed(category) => Some(ExitCode::from(*category)),
+            _ => None,
+        })
+        .unwrap_or(ExitCode::SUCCESS)
 }
 
-// TODO: logging?
-// TODO: errors?
-// TODO: possibly make custom errors (not just IO errors) that could be multiple types
-
-// region: Advent Setup Steps
-
-// Example step...
-// fn list_files() {
-//     // Test running ls
-//     println!("Running 'ls':");
-//     Command::new("ls").status().expect("ls command failed to start");
-//     println!();
-// }
-
-/// Checks to see if a folder called `day_info.project_name` is in the current directory already and if not,
-///  create a new rust project with that name using `cargo new` (with default values passed to it)
-fn create_rust_project(day_info: &DayInfo) -> io::Result<()> {
-    // Check if the folder exists in the current folder
-    let project_path = Path::new(&day_info.project_name);
-    if project_path.exists() {
-        // TODO: we want to be able to add tempating things to existing ones so maybe don't error here...
-        // ? Should I check for existence of cargo.toml?
-        println!("{}", format!("  The project/directory '{}' already exists. Not creating a new one", day_info.project_name).yellow());
-        return Ok(());
+fn print_summary(step_results: &[(&str, StepResult)]) {
+    println!("\n--------------------");
+    println!("\n{}", "Summary:".bold());
+    let name_width = step_results.iter().map(|(name, _)| name.len()).max().unwrap_or_default();
+    for (name, step_result) in step_results {
+        let result_string = match step_result {
+            StepResult::Succeeded => "succeeded".green(),
+            StepResult::Failed(category) => format!("failed ({category} error, exit code {})", category.exit_code()).red(),
+            StepResult::NotRun => "not run".yellow(),
+        };
+        println!("  {name:<name_width$}  {result_string}");
     }
+}
 
-    // TODO: maybe prompt user whether they wish to create a new project, initialize the existing folder or just exit the program
+/// Run each of the days (or every day that can be found) and print the table of results. Progress goes to stderr so that
+///  only the table ends up in stdout
+fn run_days(year: &str, days: &[u8], format: OutputFormat) -> ExitCode {
+    let (day_runs, exit_code) = run_found_days(year, days);
+    println!("{}", day_runs::format_runs(&day_runs, format));
+    exit_code
+}
 
-    // Run cargo new to create a new project
-    Command::new("cargo")
-        .args(["new", &day_info.project_name])
-        .status()
-        .expect("Failed to create new project with 'cargo new'");
+/// Run the days that were asked for (or all of them), noting any that couldn't be found or run in the exit code
+fn run_found_days(year: &str, days: &[u8]) -> (Vec<day_runs::DayRun>, ExitCode) {
+    let projects = workspace::find_projects(Path::new("."), year);
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut day_runs = Vec::new();
+    for &day_num in days {
+        if !projects.iter().any(|(project_day_num, _)| *project_day_num == day_num) {
+            eprintln!("{}", format!("Couldn't find the project for day {day_num}, skipping it").yellow());
+            exit_code = ErrorCategory::Input.into();
+        }
+    }
 
-    // Just return okay if it didn't already crash
-    Ok(())
+    for (day_num, project_path) in projects.iter().filter(|(day_num, _)| days.is_empty() || days.contains(day_num)) {
+        eprintln!("Running {}...", get_file_bg_string(project_path));
+        let day_run = day_runs::run_day(&SystemRunner, *day_num, project_path);
+        if let Some(e) = &day_run.error {
+            eprintln!("{}", format!("  Failed with error: '{e}'").red());
+            exit_code = ErrorCategory::Cargo.into();
+        }
+        day_runs.push(day_run);
+    }
+    (day_runs, exit_code)
 }
 
-/// Add a gitignore if it doesn't exist already (creating a new cargo project doesn't add a gitignore if the project is already contained in a git repo)
-fn add_gitignore(day_info: &DayInfo) -> io::Result<()> {
-    // Get the path to the gitignore
-    let gitignore_path = Path::new(&day_info.project_name).join(".gitignore");
+/// Run the days and compare each part's answer to the one in answers.toml, exiting with a failure if any of them changed
+fn verify_days(year: &str, days: &[u8], record: bool) -> ExitCode {
+    let mut answer_book = match AnswerBook::load(Path::new(answers::ANSWERS_FILE)) {
+        Ok(answer_book) => answer_book,
+        Err(e) => {
+            eprintln!("{}", format!("Couldn't read the answers: '{e}'").red());
+            return e.category().into();
+        }
+    };
 
-    if gitignore_path.exists() {
-        print_already_exists(&gitignore_path);
-    }
-    else {
-        // Create the file (with '/target' rule setup in it)
-        fs::write(&gitignore_path, "/target\n")?;
-        print_created(&gitignore_path);
+    let (day_runs, mut exit_code) = run_found_days(year, days);
+    let mut recorded = false;
+    println!();
+    for day_run in &day_runs {
+        let verdicts = answers::verify(&answer_book, year, day_run);
+        for ((part, part_name), verdict) in [(1, "part one"), (2, "part two")].into_iter().zip(verdicts) {
+            let label = format!("{} {part_name}", day_run.project_name);
+            match verdict {
+                Verdict::Matches => println!("{}", format!("  {label}: ok").green()),
+                Verdict::Differs { expected, actual } => {
+                    println!("{}", format!("  {label}: expected {expected}, but got {}", actual.as_deref().unwrap_or("nothing")).red());
+                    exit_code = ExitCode::FAILURE;
+                }
+                Verdict::Unknown => {
+                    let actual = if part == 1 { &day_run.part_one } else { &day_run.part_two };
+                    match actual {
+                        Some(actual) if record => {
+                            recorded |= answer_book.set(year, day_run.day_num, part, actual);
+                            println!("{}", format!("  {label}: recorded {actual}").yellow());
+                        }
+                        _ => println!("{}", format!("  {label}: no answer recorded").yellow()),
+                    }
+                }
+            }
+        }
     }
 
-    Ok(())
+    if recorded {
+        if let Err(e) = answer_book.save() {
+            eprintln!("{}", format!("Couldn't save the answers: '{e}'").red());
+            return e.category().into();
+        }
+    }
+    exit_code
 }
 
-fn create_readme(day_info: &DayInfo) -> io::Result<()> {
-    // Check if README already exists
-    let readme_path = Path::new(&day_info.project_name).join("README.md");
+/// Find the puzzle inputs committed to the repo and, if asked, stop tracking them and ignore them from then on. Exits with a
+///  failure while any are still tracked, so it can guard a CI run
+fn scrub_inputs(input_files: &InputFiles, untrack: bool) -> ExitCode {
+    let repo_dir = Path::new(".");
+    let found = git::repo_root(&SystemRunner, repo_dir).and_then(|repo_root| Ok((repo_root, git::tracked_files(&SystemRunner, repo_dir)?)));
+    let (repo_root, tracked_files) = match found {
+        Ok(found) => found,
+        Err(e) => {
+            println!("{}", format!("Couldn't list the files in the repo: '{e}'").red());
+            return e.category().into();
+        }
+    };
 
-    if readme_path.exists() {
-        print_already_exists(&readme_path);
+    let committed_inputs = privacy::committed_inputs(&tracked_files, &input_files.question);
+    if committed_inputs.is_empty() {
+        println!("{}", "No puzzle inputs are committed to the repo".green());
+        return ExitCode::SUCCESS;
     }
-    else {
-        // Reference the url to the page for this day's question
-        let day_url_note = format!("See the question for more details: {}", day_info.day_url);
-    
-        // Template out the README file
-        let readme_contents = format!("# {}\n{day_url_note}\n", day_info.day_title);
-    
-        // Create the file
-        fs::write(&readme_path, readme_contents)?;
-        print_created(&readme_path);
+    println!("{}", format!("Found {} committed puzzle input(s):", committed_inputs.len()).yellow());
+    for committed_input in &committed_inputs {
+        println!("  {}", get_file_bg_string(Path::new(committed_input)));
+    }
+    if !untrack {
+        println!("\nRun with --untrack to stop tracking them");
+        return ExitCode::FAILURE;
     }
 
-    // Return Ok if nothing else complained
-    Ok(())
-}
-
-fn create_inputs(day_info: &DayInfo) -> io::Result<()> {
-    // Helper function to create the input files
-    fn create_input_files<'a>(inputs_dir_path: &Path, inputs_to_create: impl IntoIterator<Item = &'a str>) -> io::Result<()> {
-        for filename in inputs_to_create {
-            let input_filepath = inputs_dir_path.join(filename);
-            if !input_filepath.exists() {
-                File::create(&input_filepath)?;
-                print_created(&input_filepath);
-            }
-            else {
-                print_already_exists(&input_filepath);
+    // Untrack the inputs and make sure they don't sneak back in with the next 'git add'
+    let rule = privacy::input_gitignore_rule(&input_files.question);
+    let scrubbed = git::untrack(&SystemRunner, repo_dir, &committed_inputs).and_then(|()| {
+        for committed_input in &committed_inputs {
+            let project_path = Path::new(committed_input).parent().and_then(Path::parent).unwrap_or(Path::new(""));
+            let gitignore_path = repo_root.join(project_path).join(".gitignore");
+            if privacy::add_gitignore_rule(&gitignore_path, &rule)? {
+                println!("  Added '{rule}' to {}", get_file_bg_string(&gitignore_path));
             }
         }
         Ok(())
+    });
+    match scrubbed {
+        Ok(()) => {
+            println!("{}", "\nThey're no longer tracked (commit to make that stick)".green());
+            println!("{}", "They're still in the history though, which only rewriting it (e.g. with git filter-repo) can remove".yellow());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            println!("{}", format!("Couldn't untrack them: '{e}'").red());
+            e.category().into()
+        }
     }
+}
 
-    // Define the input files we want to create. At the moment just the two: "example" & "question"
-    let inputs_to_create = ["example", "question"];
+/// Decrypt the days' inputs from their encrypted copies, leaving any input that's already there alone
+#[cfg(feature = "encryption")]
+fn decrypt_inputs(year: &str, days: &[u8], input_files: &InputFiles, key_file: Option<&Path>) -> ExitCode {
+    let key = match input_key_path(key_file).and_then(|key_path| input_encryption::InputKey::load(&key_path)) {
+        Ok(key) => key,
+        Err(e) => {
+            println!("{}", format!("Couldn't load the key: '{e}'").red());
+            return e.category().into();
+        }
+    };
 
-    // Check if inputs dir exists
-    let inputs_path = Path::new(&day_info.project_name).join("inputs");
-    if inputs_path.is_dir() {
-        println!("{}", format!("  Directory '{}' already exists", inputs_path.display()).yellow());
-        // If it does exist, check if example and question files exist, creating the files if not
-        create_input_files(&inputs_path, inputs_to_create)?;
-        Ok(())
-    }
-    else if inputs_path.is_file() {
-        // This shouldn't happen but at the same time don't want to just delete it...
-        // Maybe just fail this step with a note to retry running it after removing/renaming the file
-        Err(io::Error::new(
-            io::ErrorKind::AlreadyExists, 
-            "Found 'inputs' file already here. Remove it, rename it, or change it to a directory if you want to add the input files."
-        ))
+    let mut exit_code = ExitCode::SUCCESS;
+    for (day_num, project_path) in workspace::find_projects(Path::new("."), year) {
+        let input_path = project_path.join("inputs").join(&input_files.question);
+        if !(days.is_empty() || days.contains(&day_num)) || !input_encryption::encrypted_path(&input_path).exists() {
+            continue;
+        }
+        if has_content(&input_path) {
+            print_already_exists(&input_path);
+            continue;
+        }
+        match input_encryption::decrypt_input(&key, &input_path) {
+            Ok(()) => println!("{}", format!("  {} decrypted!", get_file_bg_string(&input_path)).green()),
+            Err(e) => {
+                println!("{}", format!("  Couldn't decrypt {}: '{e}'", get_file_bg_string(&input_path)).red());
+                exit_code = e.category().into();
+            }
+        }
     }
-    else {
-        // If not, create it and create files
-        println!("  Creating '{}' directory", inputs_path.display());
+    exit_code
+}
 
-        // Create the inputs dir
-        fs::create_dir(&inputs_path)?;
+#[cfg(not(feature = "encryption"))]
+fn decrypt_inputs(_year: &str, _days: &[u8], _input_files: &InputFiles, _key_file: Option<&Path>) -> ExitCode {
+    let e = scaffolder::encryption_not_built();
+    println!("{}", e.to_string().red());
+    e.category().into()
+}
 
-        // Create the input files
-        create_input_files(&inputs_path, inputs_to_create)?;
+/// Submit the answer for the part (from running the day's project if it isn't given), printing how it went
+fn submit_answer(client: &AocClient, year: &str, day_num: u8, part: u8, answer: Option<String>) -> error::Result<Outcome> {
+    let project_path = workspace::find_project(Path::new("."), year, day_num)
+        .ok_or_else(|| AdventemplateError::Input(format!("Couldn't find the project for day {day_num} (a 'day{day_num}-...' folder)")))?;
+    let mut history = SubmissionHistory::load(&project_path)?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            println!("\nRunning {} to get the answer...", get_file_bg_string(&project_path));
+            let output = submission::run_project(&SystemRunner, &project_path)?;
+            submission::scrape_answer(&output, part)
+                .ok_or_else(|| AdventemplateError::Input(format!("Couldn't find the answer to part {part} in the output:\n{output}")))?
+        }
+    };
 
-        // Temp just return ok
-        Ok(())
+    println!("\nSubmitting {} for part {part} of day {day_num}...", answer.bold());
+    let outcome = submission::submit(client, year, day_num, part, &answer, &mut history)?;
+    match outcome {
+        Outcome::Correct => {
+            println!("{}", format!("  {outcome}").green());
+
+            // Keep the right answer so later changes to the solution can be checked against it
+            let mut answer_book = AnswerBook::load(Path::new(answers::ANSWERS_FILE))?;
+            if answer_book.set(year, day_num, part, &answer) {
+                answer_book.save()?;
+                println!("  Added it to {}", get_file_bg_string(Path::new(answers::ANSWERS_FILE)));
+            }
+        }
+        ref outcome if outcome.is_wrong() => println!("{}", format!("  {outcome}").red()),
+        ref outcome => println!("{}", format!("  {outcome}").yellow()),
     }
+    Ok(outcome)
 }
-
-fn template_main_rs(day_info: &DayInfo) -> io::Result<()> {
-// fn template_main_rs(day_info: &DayInfo) -> Result<(), tera::Error> {
-    // Load the template, including it into the binary
-    println!("  Loading main.rs template file...");
-    let mut tera_template = Tera::default();
-    tera_template
-        .add_raw_template(
-            "main.rs", 
-            include_str!("../templates/main.rs")
-        )
-        .expect("Failed to load template file...");
-    // TODO: probably remove the panic causing things from this...
-
-    // Set up the context for the template to be rendered in
-    let mut context = Context::new();
-    context.insert("input_structure_name", &day_info.input_structure_name);
-
-    // Render the template
-    println!("  Rendering out main.rs template...");
-    let main_rs_contents = tera_template
-        .render("main.rs", &context)
-        .expect("Failed to render the main.rs template...");
-
-    // TODO: Check if main.rs is different than default maybe? If it is, possibly prompt the user if it should be replaced
-    // Write the template to the src/main.rs file
-    let main_rs_path = Path::new(&day_info.project_name)
-        .join("src")
-        .join("main.rs");
-    println!("  Overwriting {}...", get_file_bg_string(&main_rs_path));
-    fs::write( main_rs_path, main_rs_contents)
-        .expect("Failed to write main.rs");
-
-    // TODO: figure out errors as this one doesn't really necessarily return io errors only
-    Ok(())
-}
-
-// endregion
\ No newline at end of file
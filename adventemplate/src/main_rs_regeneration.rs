@@ -0,0 +1,157 @@
+use std::{
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use colored::Colorize;
+use diffy::{DiffOptions, PatchFormatter};
+
+/// What `cargo new` puts in src/main.rs, which is always fine to replace
+pub const CARGO_DEFAULT_MAIN_RS: &str = "fn main() {\n    println!(\"Hello, world!\");\n}\n";
+
+/// What to do when main.rs has changes that rendering the template again would throw away
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ConflictChoice {
+    /// Replace main.rs with the freshly rendered template
+    Overwrite,
+    /// Leave main.rs alone and write the rendered template next to it as main.rs.new
+    New,
+    /// Three-way merge the template changes into main.rs, keeping your edits
+    Merge,
+}
+
+/// Where the last rendered main.rs is kept in the project, to tell user edits apart from template output and to merge against
+pub fn last_rendered_path(project_path: &Path) -> PathBuf {
+    project_path.join(".adventemplate").join("main.rs")
+}
+
+pub fn read_last_rendered(project_path: &Path) -> Option<String> {
+    fs::read_to_string(last_rendered_path(project_path)).ok()
+}
+
+pub fn store_last_rendered(project_path: &Path, main_rs_contents: &str) -> io::Result<()> {
+    let last_rendered_path = last_rendered_path(project_path);
+    if let Some(parent) = last_rendered_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(last_rendered_path, main_rs_contents)
+}
+
+/// Whether main.rs can be replaced without losing anything: it's either what `cargo new` made or exactly what was rendered last
+pub fn is_safe_to_overwrite(existing_main_rs: &str, last_rendered: Option<&str>) -> bool {
+    existing_main_rs == CARGO_DEFAULT_MAIN_RS || Some(existing_main_rs) == last_rendered
+}
+
+/// A unified diff going from the current main.rs to the newly rendered one
+pub fn unified_diff(existing_main_rs: &str, rendered_main_rs: &str, color: bool) -> String {
+    let patch = DiffOptions::new()
+        .set_original_filename("src/main.rs")
+        .set_modified_filename("src/main.rs (rendered)")
+        .create_patch(existing_main_rs, rendered_main_rs);
+
+    let formatter = if color { PatchFormatter::new().with_color() } else { PatchFormatter::new() };
+    // (An explicit return: as a tail expression, the formatted patch would be dropped after `formatter` and `patch` are)
+    return formatter.fmt_patch(&patch).to_string();
+}
+
+/// Merge the changes between the last rendered template and the new one into the existing main.rs.
+///
+/// Without a record of the last render, `cargo new`'s main.rs stands in as the common ancestor. If the changes overlap,
+///  the error holds the merged file with conflict markers in it.
+pub fn three_way_merge(last_rendered: Option<&str>, existing_main_rs: &str, rendered_main_rs: &str) -> Result<String, String> {
+    diffy::merge(
+        last_rendered.unwrap_or(CARGO_DEFAULT_MAIN_RS),
+        existing_main_rs,
+        rendered_main_rs,
+    )
+}
+
+/// Ask which way to go with a conflicting main.rs. When there's no terminal to ask on, the rendered file is written to the
+///  side (the choice that can't lose anything)
+pub fn prompt_for_choice() -> io::Result<ConflictChoice> {
+    if !io::stdin().is_terminal() {
+        println!("{}", "  Not running in a terminal, so writing the rendered template to main.rs.new".yellow());
+        return Ok(ConflictChoice::New);
+    }
+
+    loop {
+        print!("  [o]verwrite main.rs, write main.rs.[n]ew, or three-way [m]erge keeping your edits? ");
+        io::stdout().flush()?;
+
+        let mut answer = String::new();
+        if io::stdin().lock().read_line(&mut answer)? == 0 {
+            return Ok(ConflictChoice::New);
+        }
+        match answer.trim().to_lowercase().as_str() {
+            "o" | "overwrite" => return Ok(ConflictChoice::Overwrite),
+            "n" | "new" => return Ok(ConflictChoice::New),
+            "m" | "merge" => return Ok(ConflictChoice::Merge),
+            _ => println!("{}", "  Please answer o, n, or m".yellow()),
+        }
+    }
+}
+
+// region: Tests
+
+#[cfg(test)]
+const RENDERED_MAIN_RS: &str = "\
+fn main() {
+    let part_one_result = part_one(input);
+}
+
+fn part_one(input: &str) -> u32 {
+    0
+}
+
+#[test]
+fn part_one_example_test() {
+    assert_eq!(part_one(example), 0);
+}
+";
+
+#[test]
+fn only_cargo_default_or_last_render_is_safe_to_overwrite() {
+    assert!(is_safe_to_overwrite(CARGO_DEFAULT_MAIN_RS, None));
+    assert!(is_safe_to_overwrite(RENDERED_MAIN_RS, Some(RENDERED_MAIN_RS)));
+    assert!(!is_safe_to_overwrite(RENDERED_MAIN_RS, None));
+    assert!(!is_safe_to_overwrite(
+        &RENDERED_MAIN_RS.replace("    0\n", "    input.len() as u32\n"),
+        Some(RENDERED_MAIN_RS)
+    ));
+}
+
+#[test]
+fn merge_keeps_user_edits_and_takes_template_changes() {
+    let edited_main_rs = RENDERED_MAIN_RS.replace("    0\n}", "    input.lines().count() as u32\n}");
+    let rerendered_main_rs = RENDERED_MAIN_RS.replace("assert_eq!(part_one(example), 0);", "assert_eq!(part_one(example), 7);");
+
+    let merged_main_rs = three_way_merge(Some(RENDERED_MAIN_RS), &edited_main_rs, &rerendered_main_rs).unwrap();
+
+    assert!(merged_main_rs.contains("input.lines().count() as u32"));
+    assert!(merged_main_rs.contains("assert_eq!(part_one(example), 7);"));
+}
+
+#[test]
+fn overlapping_changes_merge_with_conflict_markers() {
+    let edited_main_rs = RENDERED_MAIN_RS.replace("assert_eq!(part_one(example), 0);", "assert_eq!(part_one(example), 8);");
+    let rerendered_main_rs = RENDERED_MAIN_RS.replace("assert_eq!(part_one(example), 0);", "assert_eq!(part_one(example), 7);");
+
+    let conflicted_main_rs = three_way_merge(Some(RENDERED_MAIN_RS), &edited_main_rs, &rerendered_main_rs).unwrap_err();
+
+    assert!(conflicted_main_rs.contains("<<<<<<<"));
+    assert!(conflicted_main_rs.contains(">>>>>>>"));
+}
+
+#[test]
+fn diff_shows_what_would_change() {
+    let edited_main_rs = RENDERED_MAIN_RS.replace("    0\n}", "    42\n}");
+
+    let diff = unified_diff(&edited_main_rs, RENDERED_MAIN_RS, false);
+
+    assert!(diff.starts_with("--- src/main.rs\n+++ src/main.rs (rendered)\n"));
+    assert!(diff.contains("\n-    42\n+    0\n"));
+}
+
+// endregion
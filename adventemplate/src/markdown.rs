@@ -0,0 +1,220 @@
+use ego_tree::NodeRef;
+use scraper::{ElementRef, Html, Node, Selector};
+
+/// The puzzle descriptions on the page (part one, then part two once it's unlocked) as Markdown, with links that start at
+///  the root of the site pointed at `site_url` so they still work away from it
+pub fn puzzle_descriptions(day_html: &str, site_url: &str) -> Vec<String> {
+    let day_document = Html::parse_document(day_html);
+    let article_selector = Selector::parse("article.day-desc").unwrap();
+    day_document
+        .select(&article_selector)
+        .map(|article| article_to_markdown(article, site_url))
+        .collect()
+}
+
+/// The part two description from the puzzle page (the second `article.day-desc`) as Markdown, if part two is unlocked
+pub fn part_two_description(day_html: &str, site_url: &str) -> Option<String> {
+    puzzle_descriptions(day_html, site_url).into_iter().nth(1)
+}
+
+/// The site a puzzle page's url is on, e.g. `https://adventofcode.com` for `https://adventofcode.com/2022/day/6`
+pub fn site_url(day_url: &str) -> &str {
+    let after_scheme = day_url.find("://").map_or(0, |scheme_end| scheme_end + 3);
+    match day_url[after_scheme..].find('/') {
+        Some(path_start) => &day_url[..(after_scheme + path_start)],
+        None => day_url,
+    }
+}
+
+/// Turn a puzzle description into Markdown, a block (heading, paragraph, code block, or list) at a time
+pub fn article_to_markdown(article: ElementRef, site_url: &str) -> String {
+    blocks(article, site_url).join("\n\n")
+}
+
+fn blocks(element: ElementRef, site_url: &str) -> Vec<String> {
+    element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter_map(|child| block(child, site_url))
+        .collect()
+}
+
+fn block(element: ElementRef, site_url: &str) -> Option<String> {
+    let markdown = match element.value().name() {
+        "h2" => format!("## {}", collapse_whitespace(&element.text().collect::<String>()).trim_matches(|c| c == '-' || c == ' ')),
+        "pre" => code_block(&element.text().collect::<String>()),
+        "ul" | "ol" => list(element, site_url),
+        "script" | "style" => return None,
+        _ if element.children().filter_map(ElementRef::wrap).any(is_block) => blocks(element, site_url).join("\n\n"),
+        _ => collapse_whitespace(&inline(element, site_url)).trim().to_string(),
+    };
+    (!markdown.is_empty()).then_some(markdown)
+}
+
+fn is_block(element: ElementRef) -> bool {
+    matches!(element.value().name(), "p" | "pre" | "ul" | "ol" | "h2" | "div")
+}
+
+/// A fenced code block, with a fence longer than any run of backticks in the code
+fn code_block(code: &str) -> String {
+    let fence = "`".repeat(longest_backtick_run(code).max(2) + 1);
+    format!("{fence}\n{}\n{fence}", code.trim_end_matches('\n'))
+}
+
+/// A list, each item's text after its marker and any lists inside it indented to line up under that text
+fn list(element: ElementRef, site_url: &str) -> String {
+    let ordered = element.value().name() == "ol";
+    element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(|item| item.value().name() == "li")
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = if ordered { format!("{}. ", i + 1) } else { String::from("- ") };
+            let indent = " ".repeat(marker.len());
+
+            // The item's own text, then whatever blocks (like nested lists) it has in it
+            let mut text = String::new();
+            let mut nested_blocks = Vec::new();
+            for child in item.children() {
+                match ElementRef::wrap(child) {
+                    Some(child_element) if is_block(child_element) => nested_blocks.extend(block(child_element, site_url)),
+                    _ => text.push_str(&inline_node(child, site_url)),
+                }
+            }
+
+            let mut item_markdown = format!("{marker}{}", collapse_whitespace(&text).trim());
+            for nested_block in nested_blocks {
+                item_markdown.push('\n');
+                item_markdown.push_str(
+                    &nested_block.lines().map(|line| format!("{indent}{line}")).collect::<Vec<_>>().join("\n"),
+                );
+            }
+            item_markdown
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The text of the element with its `<code>`, `<em>`, and `<a>` bits marked up
+fn inline(element: ElementRef, site_url: &str) -> String {
+    element.children().map(|child| inline_node(child, site_url)).collect()
+}
+
+fn inline_node(node: NodeRef<Node>, site_url: &str) -> String {
+    match node.value() {
+        Node::Text(text) => escape(text),
+        Node::Element(_) => {
+            let element = ElementRef::wrap(node).unwrap();
+            let inner = inline(element, site_url);
+            match element.value().name() {
+                // Markdown can't emphasise inside code, so highlighted code (like the answers) is emphasised as a whole
+                "code" => {
+                    let code = inline_code(&element.text().collect::<String>());
+                    let emphasised = element.children().filter_map(ElementRef::wrap).any(|inner| inner.value().name() == "em");
+                    if emphasised { format!("*{code}*") } else { code }
+                }
+                // Emphasis has to hug the text, so any space around it goes outside the asterisks
+                "em" if inner.trim().is_empty() => inner,
+                "em" => {
+                    let trimmed = inner.trim();
+                    let leading = &inner[..inner.find(trimmed).unwrap_or_default()];
+                    let trailing = &inner[(leading.len() + trimmed.len())..];
+                    format!("{leading}*{trimmed}*{trailing}")
+                }
+                "a" => match element.value().attr("href") {
+                    Some(href) if href.starts_with('/') => format!("[{inner}]({site_url}{href})"),
+                    Some(href) => format!("[{inner}]({href})"),
+                    None => inner,
+                },
+                "br" => String::from("  \n"),
+                // The hover text on some of the jokes would be lost otherwise, so it follows in brackets
+                "span" => match element.value().attr("title") {
+                    Some(title) => format!("{inner} ({})", escape(title)),
+                    None => inner,
+                },
+                _ => inner,
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+/// Inline code, with enough backticks around it that any in the code don't end it early
+fn inline_code(code: &str) -> String {
+    let ticks = "`".repeat(longest_backtick_run(code) + 1);
+    match code.starts_with('`') || code.ends_with('`') {
+        true => format!("{ticks} {code} {ticks}"),
+        false => format!("{ticks}{code}{ticks}"),
+    }
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`').map(str::len).max().unwrap_or_default()
+}
+
+/// Escape the characters in plain text that Markdown would otherwise take as formatting
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Squash the runs of whitespace that html doesn't care about into single spaces (keeping the line breaks from `<br>`)
+fn collapse_whitespace(text: &str) -> String {
+    text.split("  \n")
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join("  \n")
+}
+
+// region: Tests
+
+#[test]
+fn day_one_matches_its_golden_file() {
+    let markdown = puzzle_descriptions(include_str!("../fixtures/day1.html"), "https://adventofcode.com").join("\n\n");
+
+    assert_eq!(format!("{markdown}\n"), include_str!("../fixtures/day1.md"));
+}
+
+#[test]
+fn day_six_matches_its_golden_file() {
+    let markdown = puzzle_descriptions(include_str!("../fixtures/day6.html"), "https://adventofcode.com").join("\n\n");
+
+    assert_eq!(format!("{markdown}\n"), include_str!("../fixtures/day6.md"));
+}
+
+#[test]
+fn day_twelve_matches_its_golden_file() {
+    let markdown = puzzle_descriptions(include_str!("../fixtures/day12.html"), "https://adventofcode.com").join("\n\n");
+
+    assert_eq!(format!("{markdown}\n"), include_str!("../fixtures/day12.md"));
+}
+
+#[test]
+fn formatting_matches_its_golden_file() {
+    let markdown = puzzle_descriptions(include_str!("../fixtures/formatting.html"), "https://adventofcode.com").join("\n\n");
+
+    assert_eq!(format!("{markdown}\n"), include_str!("../fixtures/formatting.md"));
+}
+
+#[test]
+fn only_part_one_has_no_part_two() {
+    assert_eq!(part_two_description(include_str!("../fixtures/day1.html"), "https://adventofcode.com"), None);
+    assert!(part_two_description(include_str!("../fixtures/day6.html"), "https://adventofcode.com")
+        .unwrap()
+        .starts_with("## Part Two\n\n"));
+}
+
+#[test]
+fn site_urls_come_from_day_urls() {
+    assert_eq!(site_url("https://adventofcode.com/2022/day/6"), "https://adventofcode.com");
+    assert_eq!(site_url("http://127.0.0.1:8080/2022/day/6"), "http://127.0.0.1:8080");
+}
+
+// endregion
@@ -0,0 +1,71 @@
+use std::{fs, io, path::Path};
+
+/// The tracked files that are puzzle inputs: the question input in the inputs folder of any project
+pub fn committed_inputs<'a>(tracked_files: &'a [String], question_input: &str) -> Vec<&'a str> {
+    let input_path = format!("inputs/{question_input}");
+    tracked_files
+        .iter()
+        .map(String::as_str)
+        .filter(|tracked_file| *tracked_file == input_path || tracked_file.ends_with(&format!("/{input_path}")))
+        .collect()
+}
+
+/// The gitignore rule (relative to the project) that keeps the question input out of git
+pub fn input_gitignore_rule(question_input: &str) -> String {
+    format!("/inputs/{question_input}")
+}
+
+/// Add the rule to the .gitignore (creating it if it isn't there), returning whether it had to be added
+pub fn add_gitignore_rule(gitignore_path: &Path, rule: &str) -> io::Result<bool> {
+    let gitignore = match fs::read_to_string(gitignore_path) {
+        Ok(gitignore) => gitignore,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    if gitignore.lines().any(|line| line.trim() == rule) {
+        return Ok(false);
+    }
+
+    let separator = if gitignore.is_empty() || gitignore.ends_with('\n') { "" } else { "\n" };
+    fs::write(gitignore_path, format!("{gitignore}{separator}{rule}\n"))?;
+    Ok(true)
+}
+
+// region: Tests
+
+#[test]
+fn question_inputs_are_picked_out_of_the_tracked_files() {
+    let tracked_files = [
+        "README.md",
+        "day1-calorie-counting/inputs/example",
+        "day1-calorie-counting/inputs/question",
+        "2021/day6-lanternfish/inputs/question",
+        "day2-rock-paper-scissors/inputs/question.enc",
+        "day3-rucksack-reorganization/src/question",
+        "inputs/question",
+    ]
+    .map(String::from);
+
+    assert_eq!(
+        committed_inputs(&tracked_files, "question"),
+        ["day1-calorie-counting/inputs/question", "2021/day6-lanternfish/inputs/question", "inputs/question"]
+    );
+    assert!(committed_inputs(&tracked_files, "input.txt").is_empty());
+}
+
+#[test]
+fn gitignore_rules_are_only_added_once() {
+    let project_dir = tempfile::tempdir().unwrap();
+    let gitignore_path = project_dir.path().join(".gitignore");
+    fs::write(&gitignore_path, "/target").unwrap();
+
+    assert!(add_gitignore_rule(&gitignore_path, "/inputs/question").unwrap());
+    assert!(!add_gitignore_rule(&gitignore_path, "/inputs/question").unwrap());
+    assert_eq!(fs::read_to_string(&gitignore_path).unwrap(), "/target\n/inputs/question\n");
+
+    let new_gitignore_path = project_dir.path().join("new.gitignore");
+    assert!(add_gitignore_rule(&new_gitignore_path, "/inputs/question").unwrap());
+    assert_eq!(fs::read_to_string(&new_gitignore_path).unwrap(), "/inputs/question\n");
+}
+
+// endregion
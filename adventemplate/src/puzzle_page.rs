@@ -0,0 +1,200 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    aoc_client::{self, AocClient},
+    error::{AdventemplateError, Result},
+};
+
+/// Where puzzle pages and inputs come from: the site behind `client` with a local cache of fetched pages sitting in front of it
+pub struct PuzzleSource {
+    pub client: AocClient,
+    pub cache_dir: PathBuf,
+    pub offline: bool,
+    /// Leave the cache as it is, even after fetching a page (for dry runs)
+    pub read_only: bool,
+}
+
+impl PuzzleSource {
+    /// The default cache location - the user's cache directory if there is one, otherwise a folder in the current directory
+    pub fn default_cache_dir() -> PathBuf {
+        dirs::cache_dir()
+            .map(|cache_dir| cache_dir.join("adventemplate"))
+            .unwrap_or_else(|| PathBuf::from(".adventemplate-cache"))
+    }
+
+    /// The url of the puzzle page for the given day
+    pub fn day_url(&self, year: &str, day_num: u8) -> String {
+        self.client.url(&format!("/{year}/day/{day_num}"))
+    }
+
+    /// Where the page for the given day is kept in the cache, keyed by year and then day
+    pub fn cached_page_path(&self, year: &str, day_num: u8) -> PathBuf {
+        self.cache_dir.join(year).join(format!("day{day_num}.html"))
+    }
+
+    /// Get the html of the puzzle page for the given day.
+    ///
+    /// When offline, this only reads from the cache. Otherwise the page is fetched from the site and the cache is
+    ///  refreshed with whatever came back.
+    pub fn get_day_page(&self, year: &str, day_num: u8) -> Result<String> {
+        let cached_page_path = self.cached_page_path(year, day_num);
+
+        if self.offline {
+            return fs::read_to_string(&cached_page_path).map_err(|e| {
+                AdventemplateError::Io(io::Error::new(
+                    e.kind(),
+                    format!(
+                        "Running offline, but there's no cached page for day {day_num} of {year} at '{}': {e}",
+                        cached_page_path.display()
+                    ),
+                ))
+            });
+        }
+
+        // Fetch the page, treating anything but a success status as a failure
+        let day_html = self.client.get_text(&format!("/{year}/day/{day_num}"))?;
+
+        // Keep a copy of the page around for later offline runs
+        if !self.read_only {
+            store_page(&cached_page_path, &day_html)?;
+        }
+
+        Ok(day_html)
+    }
+
+    /// Download the puzzle input for the given day. Inputs differ per user, so this needs the session token
+    pub fn download_input(&self, year: &str, day_num: u8) -> Result<String> {
+        if self.offline {
+            return Err(AdventemplateError::Input(String::from(
+                "Running offline, so the puzzle input can't be downloaded",
+            )));
+        }
+        if self.client.session.is_none() {
+            return Err(AdventemplateError::Input(format!(
+                "No session token found to download the puzzle input with. Set {} or put it in the session file",
+                aoc_client::SESSION_ENV_VAR
+            )));
+        }
+
+        self.client.get_text(&format!("/{year}/day/{day_num}/input"))
+    }
+}
+
+fn store_page(cached_page_path: &Path, day_html: &str) -> io::Result<()> {
+    if let Some(parent) = cached_page_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(cached_page_path, day_html)
+}
+
+// region: Tests
+
+#[cfg(test)]
+use crate::test_server::TestServer;
+
+#[test]
+fn online_fetch_populates_the_cache() {
+    let server = TestServer::start([("/2022/day/6", (200, "<h2>--- Day 6: Tuning Trouble ---</h2>"))]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let source = PuzzleSource {
+        client: AocClient::new(server.base_url(), None),
+        cache_dir: cache_dir.path().to_path_buf(),
+        offline: false,
+        read_only: false,
+    };
+
+    let day_html = source.get_day_page("2022", 6).unwrap();
+
+    assert_eq!(day_html, "<h2>--- Day 6: Tuning Trouble ---</h2>");
+    let requests = server.requests();
+    assert_eq!((requests[0].method.as_str(), requests[0].path.as_str()), ("GET", "/2022/day/6"));
+    assert_eq!(
+        fs::read_to_string(cache_dir.path().join("2022").join("day6.html")).unwrap(),
+        day_html
+    );
+}
+
+#[test]
+fn offline_reads_only_from_the_cache() {
+    // Point at a server that would fail every request to be sure nothing goes over the network
+    let server = TestServer::start([]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let source = PuzzleSource {
+        client: AocClient::new(server.base_url(), None),
+        cache_dir: cache_dir.path().to_path_buf(),
+        offline: true,
+        read_only: false,
+    };
+    store_page(&source.cached_page_path("2022", 14), "cached page").unwrap();
+
+    assert_eq!(source.get_day_page("2022", 14).unwrap(), "cached page");
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn offline_without_a_cached_page_fails() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let source = PuzzleSource {
+        client: AocClient::new(String::from("http://127.0.0.1:9"), None),
+        cache_dir: cache_dir.path().to_path_buf(),
+        offline: true,
+        read_only: false,
+    };
+
+    let error = source.get_day_page("2022", 1).unwrap_err();
+    assert!(matches!(error, AdventemplateError::Io(e) if e.kind() == io::ErrorKind::NotFound));
+}
+
+#[test]
+fn failed_fetch_leaves_the_cache_alone() {
+    let server = TestServer::start([("/2022/day/25", (404, "Not unlocked yet"))]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let source = PuzzleSource {
+        client: AocClient::new(server.base_url(), None),
+        cache_dir: cache_dir.path().to_path_buf(),
+        offline: false,
+        read_only: false,
+    };
+
+    assert!(matches!(source.get_day_page("2022", 25), Err(AdventemplateError::Network(_))));
+    assert!(!source.cached_page_path("2022", 25).exists());
+}
+
+#[test]
+fn read_only_fetch_leaves_the_cache_alone() {
+    let server = TestServer::start([("/2022/day/6", (200, "page"))]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let source = PuzzleSource {
+        client: AocClient::new(server.base_url(), None),
+        cache_dir: cache_dir.path().to_path_buf(),
+        offline: false,
+        read_only: true,
+    };
+
+    assert_eq!(source.get_day_page("2022", 6).unwrap(), "page");
+    assert!(!source.cached_page_path("2022", 6).exists());
+}
+
+#[test]
+fn input_download_needs_a_session() {
+    let server = TestServer::start([("/2022/day/1/input", (200, "1000\n"))]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let mut source = PuzzleSource {
+        client: AocClient::new(server.base_url(), None),
+        cache_dir: cache_dir.path().to_path_buf(),
+        offline: false,
+        read_only: false,
+    };
+
+    let error = source.download_input("2022", 1).unwrap_err();
+    assert!(matches!(error, AdventemplateError::Input(_)));
+    assert!(server.requests().is_empty());
+
+    source.client.session = Some(String::from("token"));
+    assert_eq!(source.download_input("2022", 1).unwrap(), "1000\n");
+}
+
+// endregion
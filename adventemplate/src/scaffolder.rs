@@ -0,0 +1,1293 @@
+use std::{
+    fs::{File, self},
+    io,
+    path::{Path, PathBuf},
+};
+
+use colored::{Colorize, ColoredString};
+use tera::Context;
+
+use crate::{
+    aoc_client,
+    command_runner::{CommandRunner, SystemRunner},
+    config::{Config, InputFiles},
+    day_title::DayTitle,
+    error::{self, AdventemplateError, ErrorCategory},
+    example_input,
+    expected_answers,
+    git,
+    input_structure_name,
+    main_rs_regeneration::{self, ConflictChoice},
+    markdown,
+    puzzle_page::PuzzleSource,
+    templates::{self, TemplateSet},
+    workspace::{self, MemberRegistration, ProjectLayout},
+};
+#[cfg(feature = "encryption")]
+use crate::input_encryption;
+
+/// The names the setup steps go by on the command line, in the order they run
+pub const STEP_NAMES: [&str; 11] = ["cargo", "gitignore", "readme", "inputs", "examples", "main", "files", "update", "workspace", "encrypt", "git"];
+
+/// The steps that only run when asked for with --with (or --only)
+pub const OPTIONAL_STEP_NAMES: [&str; 4] = ["update", "workspace", "encrypt", "git"];
+
+/// The steps that render templates, which need the input_structure_name
+pub const NAMED_STEP_NAMES: [&str; 3] = ["readme", "main", "files"];
+
+/// Sets days up under a root directory, getting the puzzle pages from the puzzle source and running cargo and git through the
+///  runner. Everything the steps touch goes through these, so a scaffolder pointed at a temp dir with stand-ins for the site
+///  and the programs can run every step without the network or a real `cargo new`
+pub struct Scaffolder {
+    /// The directory the projects (and the workspace's `Cargo.toml`) go in, which is also where git is run
+    pub root: PathBuf,
+    pub puzzle_source: PuzzleSource,
+    pub runner: Box<dyn CommandRunner>,
+}
+
+impl Scaffolder {
+    /// A scaffolder for the root directory that runs the real cargo and git
+    pub fn new(root: impl Into<PathBuf>, puzzle_source: PuzzleSource) -> Self {
+        Scaffolder {
+            root: root.into(),
+            puzzle_source,
+            runner: Box::new(SystemRunner),
+        }
+    }
+
+    /// The path to something relative to the root. Paths are left as they are for the current directory, so what gets
+    ///  printed doesn't start with `./`
+    pub fn in_root(&self, relative_path: impl AsRef<Path>) -> PathBuf {
+        match self.root == Path::new(".") {
+            true => relative_path.as_ref().to_path_buf(),
+            false => self.root.join(relative_path),
+        }
+    }
+
+    /// Where the day's project is
+    pub fn project_dir(&self, day_info: &DayInfo) -> PathBuf {
+        self.in_root(&day_info.project_path)
+    }
+
+    /// Request and parse the page for the day, getting the title & project name from it. The input_structure_name is asked
+    ///  for (with suggestions from the puzzle text) if it isn't given and it's needed
+    pub fn look_up_day(
+        &self,
+        year: &str,
+        day_num: u8,
+        layout: ProjectLayout,
+        input_structure_name: Option<String>,
+        needs_input_structure_name: bool,
+    ) -> error::Result<DayInfo> {
+        // Determine the link for the day
+        let day_url = self.puzzle_source.day_url(year, day_num);
+
+        // Get the title of this day's question from the html of the page (fetched or pulled from the cache), and the project
+        //  name from that
+        let day_html = self.puzzle_source.get_day_page(year, day_num)?;
+        let title = DayTitle::parse(&day_html)?;
+        let day_title = format!("Advent of Code - {title}");
+        let project_name = title.project_name();
+
+        // Ask what to call the input if it wasn't given, suggesting names from the puzzle text
+        let input_structure_name = match input_structure_name {
+            Some(input_structure_name) => input_structure_name,
+            None if needs_input_structure_name => {
+                input_structure_name::prompt_for_name(&day_html).map_err(|e| AdventemplateError::Input(e.to_string()))?
+            }
+            None => String::new(),
+        };
+
+        // Return the info for the day
+        Ok(DayInfo {
+            year: year.to_string(),
+            day_num,
+            project_path: layout.project_path(year, &project_name),
+            project_name,
+            day_title,
+            day_url,
+            input_structure_name,
+            day_html,
+            example_index: None,
+            regenerate_main: false,
+            on_conflict: None,
+            templates: TemplateSet::default(),
+            input_files: InputFiles::default(),
+            gitignore: Config::default().gitignore,
+            private_files: Config::default().private_files,
+            key_file: None,
+        })
+    }
+
+    /// Run the steps in order, returning how each of them went. Once a critical step fails, the rest are left as not run
+    pub fn run(&self, day_info: &DayInfo, steps: Vec<SetupStep>) -> Vec<(&'static str, StepResult)> {
+        let mut step_results = Vec::new();
+        let mut stopped = false;
+        for step in steps {
+            // Once a critical step has failed, the rest are just noted as not run
+            if stopped {
+                step_results.push((step.name, StepResult::NotRun));
+                continue;
+            }
+
+            println!("\n--------------------");
+            println!("\n{}", step.step_message.blue());
+            // ? Maybe make all text outputted from the step execution indented
+            match (step.step_executor)(self, day_info) {
+                Ok(()) => {
+                    println!("{}", "Success!".green());
+                    step_results.push((step.name, StepResult::Succeeded));
+                }
+                Err(e) => {
+                    println!("{}", format!("Failed with error: '{e}'").red());
+                    step_results.push((step.name, StepResult::Failed(e.category())));
+                    if step.critical {
+                        println!("{}", "\nThere was an error in a critical step. Stopping execution.".red());
+                        stopped = true;
+                    }
+                },
+            }
+        }
+        step_results
+    }
+
+    /// Lay out what each step would do without doing any of it, returning the files each step would touch
+    pub fn plan(&self, day_info: &DayInfo, steps: Vec<SetupStep>) -> Vec<(&'static str, error::Result<Vec<PlannedFile>>)> {
+        let mut step_plans = Vec::new();
+        for step in steps {
+            println!("\n{} {}", format!("[{}]", step.name).blue(), step.step_message);
+            let planned_files = (step.step_planner)(self, day_info);
+            match &planned_files {
+                Ok(planned_files) if planned_files.is_empty() => println!("  Nothing to do"),
+                Ok(planned_files) => {
+                    for planned_file in planned_files {
+                        println!("  {}", planned_file.describe());
+                    }
+                }
+                Err(e) => println!("{}", format!("  Couldn't work out what this step would do: '{e}'").red()),
+            }
+            step_plans.push((step.name, planned_files));
+        }
+        step_plans
+    }
+
+    /// Check there aren't any changes in the working tree, which committing the project on its own branch would sweep up (or
+    ///  trip over)
+    pub fn ensure_clean(&self) -> error::Result<()> {
+        match git::is_clean(self.runner.as_ref(), &self.root)? {
+            true => Ok(()),
+            false => Err(AdventemplateError::Git(String::from(
+                "The working tree has changes in it. Commit or stash them before running the git step",
+            ))),
+        }
+    }
+
+    /// Stage the project's files with `git add` (which leaves out anything its .gitignore rules out, and the private files).
+    ///  Not being able to is just noted, since the project itself is set up either way
+    pub fn stage_project(&self, day_info: &DayInfo) {
+        println!("\n--------------------");
+        println!("\n{}", "Staging the project with git...".blue());
+        match git::stage(self.runner.as_ref(), &self.root, &day_info.project_path, &day_info.private_files) {
+            Ok(()) => println!("{}", format!("  Staged {}!", get_file_bg_string(&self.project_dir(day_info))).green()),
+            Err(e) => println!("{}", format!("  Couldn't stage the project: '{e}'").yellow()),
+        }
+    }
+}
+
+/// Every setup step, in the order they run
+pub fn all_steps() -> Vec<SetupStep> {
+    vec![
+        SetupStep {
+            name: "cargo",
+            step_message: String::from("Creating new rust project with cargo..."),
+            step_executor: create_rust_project,
+            step_planner: plan_rust_project,
+            critical: true,
+        },
+        SetupStep {
+            name: "gitignore",
+            step_message: String::from("Adding gitignore to project..."),
+            step_executor: add_gitignore,
+            step_planner: plan_gitignore,
+            critical: false,
+        },
+        SetupStep {
+            name: "readme",
+            step_message: String::from("Creating README for project..."),
+            step_executor: create_readme,
+            step_planner: plan_readme,
+            critical: false,
+        },
+        SetupStep {
+            name: "inputs",
+            step_message: String::from("Creating inputs folder..."),
+            step_executor: create_inputs,
+            step_planner: plan_inputs,
+            critical: false,
+        },
+        SetupStep {
+            name: "examples",
+            step_message: String::from("Extracting example input from the puzzle page..."),
+            step_executor: extract_example_inputs,
+            step_planner: plan_example_inputs,
+            critical: false,
+        },
+        SetupStep {
+            name: "main",
+            step_message: String::from("Templating main.rs file..."),
+            step_executor: template_main_rs,
+            step_planner: plan_main_rs,
+            critical: false,
+        },
+        SetupStep {
+            name: "files",
+            step_message: String::from("Rendering the rest of the template files..."),
+            step_executor: render_template_files,
+            step_planner: plan_template_files,
+            critical: false,
+        },
+        SetupStep {
+            name: "update",
+            step_message: String::from("Updating the project with part two of the puzzle..."),
+            step_executor: update_for_part_two,
+            step_planner: plan_update_for_part_two,
+            critical: false,
+        },
+        SetupStep {
+            name: "workspace",
+            step_message: String::from("Registering project in the workspace..."),
+            step_executor: register_workspace_member,
+            step_planner: plan_workspace_member,
+            critical: false,
+        },
+        SetupStep {
+            name: "encrypt",
+            step_message: String::from("Encrypting the puzzle input..."),
+            step_executor: encrypt_question_input,
+            step_planner: plan_encrypt_question_input,
+            critical: false,
+        },
+        SetupStep {
+            name: "git",
+            step_message: String::from("Committing the project on a branch for the day..."),
+            step_executor: commit_to_day_branch,
+            step_planner: plan_commit_to_day_branch,
+            critical: false,
+        },
+    ]
+}
+
+/// The steps to run: every step but the optional ones (plus any asked for with `with`), or the default steps from the config
+///  instead, or just the `only` ones, leaving out the `skip` ones in any case
+pub fn select_steps(only: &[String], skip: &[String], with: &[String], default_steps: Option<&[String]>) -> error::Result<Vec<SetupStep>> {
+    if let Some(unknown_step) = default_steps.into_iter().flatten().find(|name| !STEP_NAMES.contains(&name.as_str())) {
+        return Err(AdventemplateError::Input(format!(
+            "The config file's steps include '{unknown_step}', which isn't one of {}",
+            STEP_NAMES.join(", ")
+        )));
+    }
+    Ok(all_steps()
+        .into_iter()
+        .filter(|step| match (only.is_empty(), default_steps) {
+            (true, None) => !OPTIONAL_STEP_NAMES.contains(&step.name) || with.iter().any(|name| name == step.name),
+            (true, Some(default_steps)) => default_steps.iter().chain(with).any(|name| name == step.name),
+            (false, _) => only.iter().any(|name| name == step.name),
+        })
+        .filter(|step| !skip.iter().any(|name| name == step.name))
+        .collect())
+}
+
+pub struct DayInfo {
+    pub year: String,
+    pub day_num: u8,
+    pub project_name: String,
+    /// Where the project is, relative to the scaffolder's root (just the project name unless the layout nests it)
+    pub project_path: String,
+    pub day_title: String,
+    pub day_url: String,
+    pub input_structure_name: String,
+    pub day_html: String,
+    pub example_index: Option<usize>,
+    pub regenerate_main: bool,
+    pub on_conflict: Option<ConflictChoice>,
+    pub templates: TemplateSet,
+    pub input_files: InputFiles,
+    /// The rules written into the project's .gitignore
+    pub gitignore: Vec<String>,
+    /// The files in the project that are never staged, like the puzzle input
+    pub private_files: Vec<String>,
+    /// The key file inputs are encrypted with, when it isn't the default one
+    pub key_file: Option<PathBuf>,
+}
+
+pub struct SetupStep {
+    pub name: &'static str,
+    pub step_message: String,
+    pub step_executor: fn(&Scaffolder, &DayInfo) -> error::Result<()>,
+    /// Work out the files the step would touch without touching them, for dry runs
+    pub step_planner: fn(&Scaffolder, &DayInfo) -> error::Result<Vec<PlannedFile>>,
+    pub critical: bool,
+}
+
+/// How a setup step went, for the summary at the end
+#[derive(Debug, PartialEq, Eq)]
+pub enum StepResult {
+    Succeeded,
+    Failed(ErrorCategory),
+    NotRun,
+}
+
+/// What a step would do to a file
+#[derive(Debug, PartialEq, Eq)]
+pub enum FileAction {
+    Create,
+    Overwrite,
+    Update,
+    Ask,
+    Skip,
+    /// Commit the file on the named branch
+    Commit(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct PlannedFile {
+    pub path: PathBuf,
+    pub action: FileAction,
+}
+
+impl PlannedFile {
+    pub fn new(path: PathBuf, action: FileAction) -> Self {
+        PlannedFile { path, action }
+    }
+
+    /// A planned file that gets created if it isn't there yet and skipped if it is
+    pub fn create_if_missing(path: PathBuf) -> Self {
+        let action = if path.exists() { FileAction::Skip } else { FileAction::Create };
+        PlannedFile { path, action }
+    }
+
+    pub fn describe(&self) -> String {
+        let file_string = get_file_bg_string(&self.path);
+        match self.action {
+            FileAction::Create => format!("would create {file_string}").green().to_string(),
+            FileAction::Overwrite => format!("would overwrite {file_string}").red().to_string(),
+            FileAction::Update => format!("would update {file_string}").yellow().to_string(),
+            FileAction::Ask => format!("would ask before changing {file_string}").yellow().to_string(),
+            FileAction::Skip => format!("would leave {file_string} as it is"),
+            FileAction::Commit(ref branch) => format!("would commit {file_string} on the '{branch}' branch").green().to_string(),
+        }
+    }
+}
+
+/// The context every template file is rendered in, so all of a flavour's files can use the same info about the day
+fn template_context(day_info: &DayInfo, expected_answers: &expected_answers::ExpectedAnswers) -> Context {
+    let mut context = Context::new();
+    context.insert("year", &day_info.year);
+    context.insert("day_num", &day_info.day_num);
+    context.insert("project_name", &day_info.project_name);
+    context.insert("day_title", &day_info.day_title);
+    context.insert("day_url", &day_info.day_url);
+    context.insert("input_structure_name", &day_info.input_structure_name);
+    context.insert("example_input", &day_info.input_files.example);
+    context.insert("question_input", &day_info.input_files.question);
+    // Part one's description, so the README has a copy of the puzzle that can be read offline
+    let descriptions = markdown::puzzle_descriptions(&day_info.day_html, markdown::site_url(&day_info.day_url));
+    context.insert("description", descriptions.first().map(String::as_str).unwrap_or_default());
+    for (key, answer) in [("part_one_expected", &expected_answers.part_one), ("part_two_expected", &expected_answers.part_two)] {
+        let expected = answer.as_deref().map(expected_answers::rust_literal).unwrap_or_else(|| String::from("0"));
+        context.insert(key, &expected);
+    }
+    context
+}
+
+pub fn get_file_bg_string(file_path: &Path) -> ColoredString {
+    file_path.to_str().unwrap().on_truecolor(60, 70, 80)
+}
+
+pub fn print_already_exists(file_path: &Path) {
+    println!(
+        "{}",
+        format!("  {} already exists, skipping...", get_file_bg_string(file_path)).yellow()
+    );
+}
+
+pub fn print_created(file_path: &Path) {
+    println!(
+        "{}",
+        format!("  {} created!", get_file_bg_string(file_path)).green()
+    );
+}
+
+/// Whether the file is there and has something in it
+pub fn has_content(file_path: &Path) -> bool {
+    fs::metadata(file_path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+#[cfg(not(feature = "encryption"))]
+pub fn encryption_not_built() -> AdventemplateError {
+    AdventemplateError::Input(String::from(
+        "adventemplate was built without encryption. Install it with '--features encryption' to encrypt and decrypt inputs",
+    ))
+}
+
+/// The key file that was given, or the default one
+#[cfg(feature = "encryption")]
+pub fn input_key_path(key_file: Option<&Path>) -> error::Result<PathBuf> {
+    key_file
+        .map(Path::to_path_buf)
+        .or_else(input_encryption::default_key_file)
+        .ok_or_else(|| AdventemplateError::Input(String::from("There's no user config directory for the key, so give one with --key-file")))
+}
+
+// TODO: logging?
+
+// region: Advent Setup Steps
+
+// Example step...
+// fn list_files() {
+//     // Test running ls
+//     println!("Running 'ls':");
+//     Command::new("ls").status().expect("ls command failed to start");
+//     println!();
+// }
+
+/// Checks to see if a folder at `day_info.project_path` is in the root already and if not, create a new rust project with
+///  that name using `cargo new` (with default values passed to it)
+fn create_rust_project(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    // Check if the folder exists in the root
+    if scaffolder.project_dir(day_info).exists() {
+        // TODO: we want to be able to add tempating things to existing ones so maybe don't error here...
+        // ? Should I check for existence of cargo.toml?
+        println!("{}", format!("  The project/directory '{}' already exists. Not creating a new one", day_info.project_path).yellow());
+        return Ok(());
+    }
+
+    // TODO: maybe prompt user whether they wish to create a new project, initialize the existing folder or just exit the program
+
+    // Run cargo new to create a new project
+    let output = scaffolder
+        .runner
+        .run("cargo", &["new", &day_info.project_path, "--name", &day_info.project_name], &scaffolder.root)
+        .map_err(|e| AdventemplateError::Cargo(format!("Failed to run 'cargo new': {e}")))?;
+    if !output.success {
+        return Err(AdventemplateError::Cargo(format!(
+            "'cargo new' didn't manage to create the project ({}): {}",
+            output.status,
+            output.stderr.trim()
+        )));
+    }
+    // Pass on what cargo had to say about it
+    for line in output.stderr.lines().filter(|line| !line.trim().is_empty()) {
+        println!("  {}", line.trim());
+    }
+
+    Ok(())
+}
+
+fn plan_rust_project(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    let project_path = scaffolder.project_dir(day_info);
+    if project_path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(vec![
+        PlannedFile::new(project_path.join("Cargo.toml"), FileAction::Create),
+        PlannedFile::new(project_path.join("src").join("main.rs"), FileAction::Create),
+    ])
+}
+
+/// Add a gitignore if it doesn't exist already (creating a new cargo project doesn't add a gitignore if the project is already contained in a git repo)
+fn add_gitignore(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    // Get the path to the gitignore
+    let gitignore_path = scaffolder.project_dir(day_info).join(".gitignore");
+
+    if gitignore_path.exists() {
+        print_already_exists(&gitignore_path);
+    }
+    else {
+        // Create the file (with rules for '/target' and adventemplate's own files by default, or the config's rules)
+        let gitignore_contents = day_info.gitignore.iter().map(|rule| format!("{rule}\n")).collect::<String>();
+        fs::write(&gitignore_path, gitignore_contents)?;
+        print_created(&gitignore_path);
+    }
+
+    Ok(())
+}
+
+fn plan_gitignore(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    Ok(vec![PlannedFile::create_if_missing(scaffolder.project_dir(day_info).join(".gitignore"))])
+}
+
+fn create_readme(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    // Check if README already exists
+    let readme_path = scaffolder.project_dir(day_info).join("README.md");
+
+    if readme_path.exists() {
+        print_already_exists(&readme_path);
+    }
+    else {
+        // Template out the README file (which links to the page for this day's question unless the flavour says otherwise)
+        let expected_answers = expected_answers::find_expected_answers(&day_info.day_html);
+        let readme_contents = day_info.templates.render("README.md", &template_context(day_info, &expected_answers))?;
+
+        // Create the file
+        fs::write(&readme_path, readme_contents)?;
+        print_created(&readme_path);
+    }
+
+    // Return Ok if nothing else complained
+    Ok(())
+}
+
+fn plan_readme(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    Ok(vec![PlannedFile::create_if_missing(scaffolder.project_dir(day_info).join("README.md"))])
+}
+
+/// Create the inputs folder with the "example" & "question" files in it (or whatever the config calls them), downloading
+///  the puzzle input into "question" when the file doesn't have anything in it yet
+fn create_inputs(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    // Helper function to create the input files
+    fn create_input_files<'a>(inputs_dir_path: &Path, inputs_to_create: impl IntoIterator<Item = &'a str>) -> io::Result<()> {
+        for filename in inputs_to_create {
+            let input_filepath = inputs_dir_path.join(filename);
+            if !input_filepath.exists() {
+                File::create(&input_filepath)?;
+                print_created(&input_filepath);
+            }
+            else {
+                print_already_exists(&input_filepath);
+            }
+        }
+        Ok(())
+    }
+
+    // Define the input files we want to create. At the moment just the two: "example" & "question"
+    let inputs_to_create = [day_info.input_files.example.as_str(), day_info.input_files.question.as_str()];
+
+    // Check if inputs dir exists
+    let inputs_path = scaffolder.project_dir(day_info).join("inputs");
+    if inputs_path.is_dir() {
+        println!("{}", format!("  Directory '{}' already exists", inputs_path.display()).yellow());
+        // If it does exist, check if example and question files exist, creating the files if not
+        create_input_files(&inputs_path, inputs_to_create)?;
+    }
+    else if inputs_path.is_file() {
+        // This shouldn't happen but at the same time don't want to just delete it...
+        // Maybe just fail this step with a note to retry running it after removing/renaming the file
+        return Err(AdventemplateError::Io(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "Found 'inputs' file already here. Remove it, rename it, or change it to a directory if you want to add the input files."
+        )));
+    }
+    else {
+        // If not, create it and create files
+        println!("  Creating '{}' directory", inputs_path.display());
+
+        // Create the inputs dir
+        fs::create_dir(&inputs_path)?;
+
+        // Create the input files
+        create_input_files(&inputs_path, inputs_to_create)?;
+    }
+
+    // Fill in the question input from the site unless it's already been filled in
+    download_question_input(scaffolder, day_info, &inputs_path.join(&day_info.input_files.question))
+}
+
+fn plan_inputs(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    let inputs_path = scaffolder.project_dir(day_info).join("inputs");
+    let question_path = inputs_path.join(&day_info.input_files.question);
+
+    // The question file gets the puzzle input downloaded into it if it's empty
+    let question_action = match fs::metadata(&question_path) {
+        Ok(metadata) if metadata.len() > 0 => FileAction::Skip,
+        Ok(_) => FileAction::Update,
+        Err(_) => FileAction::Create,
+    };
+    Ok(vec![
+        PlannedFile::create_if_missing(inputs_path.join(&day_info.input_files.example)),
+        PlannedFile::new(question_path, question_action),
+    ])
+}
+
+/// Download the puzzle input into the question file, skipping the download when the file already has content
+fn download_question_input(scaffolder: &Scaffolder, day_info: &DayInfo, question_path: &Path) -> error::Result<()> {
+    if fs::metadata(question_path)?.len() > 0 {
+        println!("  {} already has the puzzle input in it, skipping download...", get_file_bg_string(question_path));
+        return Ok(());
+    }
+
+    // Without a way to get at the input, leave the file for filling in by hand
+    let puzzle_source = &scaffolder.puzzle_source;
+    if puzzle_source.offline {
+        println!("{}", "  Running offline, so the puzzle input will need to be added by hand".yellow());
+        return Ok(());
+    }
+    if puzzle_source.client.session.is_none() {
+        println!(
+            "{}",
+            format!("  No session token found ({} or the session file), so the puzzle input will need to be added by hand", aoc_client::SESSION_ENV_VAR).yellow()
+        );
+        return Ok(());
+    }
+
+    println!("  Downloading the puzzle input for day {}...", day_info.day_num);
+    let question_input = puzzle_source.download_input(&day_info.year, day_info.day_num)?;
+    fs::write(question_path, question_input)?;
+    println!("{}", format!("  {} filled in with the puzzle input!", get_file_bg_string(question_path)).green());
+
+    Ok(())
+}
+
+/// Pull the example input(s) out of the puzzle page into the inputs folder: the main one into "example" and any others into
+///  "example2", "example3", etc. Example files that already have something in them are left alone
+fn extract_example_inputs(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    let example_inputs = find_example_inputs(scaffolder, day_info)?;
+    if example_inputs.is_empty() {
+        println!("{}", "  Couldn't find an example on the puzzle page, leaving the example input as it is".yellow());
+        return Ok(());
+    }
+
+    // Write out each example (the inputs folder is normally already there from the inputs step)
+    fs::create_dir_all(scaffolder.project_dir(day_info).join("inputs"))?;
+    for (example_path, example) in example_inputs {
+        if has_content(&example_path) {
+            print_already_exists(&example_path);
+        }
+        else {
+            fs::write(&example_path, example)?;
+            print_created(&example_path);
+        }
+    }
+
+    Ok(())
+}
+
+fn plan_example_inputs(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    Ok(find_example_inputs(scaffolder, day_info)?
+        .into_iter()
+        .map(|(example_path, _)| {
+            let action = match (example_path.exists(), has_content(&example_path)) {
+                (_, true) => FileAction::Skip,
+                (true, false) => FileAction::Update,
+                (false, _) => FileAction::Create,
+            };
+            PlannedFile::new(example_path, action)
+        })
+        .collect())
+}
+
+/// The example inputs from the puzzle page paired up with the files they go in
+fn find_example_inputs(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<(PathBuf, String)>> {
+    // Either take the code block that was asked for or find the blocks that look like examples
+    let examples = match day_info.example_index {
+        Some(example_index) => {
+            let code_blocks = example_input::code_blocks(&day_info.day_html);
+            let example = example_index
+                .checked_sub(1)
+                .and_then(|index| code_blocks.get(index))
+                .ok_or_else(|| AdventemplateError::Input(
+                    format!("There is no code block {example_index} on the page, there are only {}", code_blocks.len()),
+                ))?;
+            vec![example.clone()]
+        }
+        None => example_input::find_examples(&day_info.day_html),
+    };
+
+    let inputs_path = scaffolder.project_dir(day_info).join("inputs");
+    Ok(examples
+        .into_iter()
+        .enumerate()
+        .map(|(i, example)| match i {
+            0 => (inputs_path.join(&day_info.input_files.example), example),
+            _ => (inputs_path.join(format!("{}{}", day_info.input_files.example, i + 1)), example),
+        })
+        .collect())
+}
+
+fn template_main_rs(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    // Look for the answers to the example in the puzzle text so the example tests can check against them
+    let expected_answers = expected_answers::find_expected_answers(&day_info.day_html);
+
+    // Render the template (from the flavour's files, which were loaded up front)
+    println!("  Rendering out main.rs template...");
+    let main_rs_contents = day_info.templates.render("main.rs", &template_context(day_info, &expected_answers))?;
+
+    // Work out whether writing the template over main.rs would lose anything
+    let project_path = scaffolder.project_dir(day_info);
+    let main_rs_path = project_path
+        .join("src")
+        .join("main.rs");
+    let last_rendered = main_rs_regeneration::read_last_rendered(&project_path);
+    let existing_main_rs = match fs::read_to_string(&main_rs_path) {
+        Ok(existing_main_rs) if !main_rs_regeneration::is_safe_to_overwrite(&existing_main_rs, last_rendered.as_deref()) => existing_main_rs,
+        _ => {
+            // Nothing to lose, so write the template to the src/main.rs file
+            println!("  Writing {}...", get_file_bg_string(&main_rs_path));
+            return write_rendered_main_rs(&project_path, &main_rs_path, &main_rs_contents);
+        }
+    };
+
+    // If main.rs has been templated already, only fill in the answers that weren't known yet (like part two's, once it unlocks)
+    if existing_main_rs.contains("fn part_one_example_test()") && !day_info.regenerate_main {
+        println!("  {} was already templated, filling in any newly found example answers...", get_file_bg_string(&main_rs_path));
+        let filled_in_main_rs = expected_answers::fill_in_expected_answers(&existing_main_rs, &expected_answers);
+        if filled_in_main_rs != existing_main_rs {
+            fs::write(&main_rs_path, filled_in_main_rs)?;
+            println!("{}", format!("  {} updated!", get_file_bg_string(&main_rs_path)).green());
+        }
+        return Ok(());
+    }
+
+    // Otherwise there are edits in the way, so show what would change and check what to do about it
+    println!(
+        "{}",
+        format!("  {} has changes that rendering the template would throw away:", get_file_bg_string(&main_rs_path)).yellow()
+    );
+    println!("{}", main_rs_regeneration::unified_diff(&existing_main_rs, &main_rs_contents, true));
+    let choice = match day_info.on_conflict {
+        Some(choice) => choice,
+        None => main_rs_regeneration::prompt_for_choice()?,
+    };
+
+    match choice {
+        ConflictChoice::Overwrite => {
+            println!("  Overwriting {}...", get_file_bg_string(&main_rs_path));
+            write_rendered_main_rs(&project_path, &main_rs_path, &main_rs_contents)
+        }
+        ConflictChoice::New => {
+            let new_main_rs_path = main_rs_path.with_extension("rs.new");
+            fs::write(&new_main_rs_path, &main_rs_contents)?;
+            print_created(&new_main_rs_path);
+            Ok(())
+        }
+        ConflictChoice::Merge => {
+            println!("  Merging the template into {}...", get_file_bg_string(&main_rs_path));
+            let merged_main_rs = main_rs_regeneration::three_way_merge(last_rendered.as_deref(), &existing_main_rs, &main_rs_contents);
+            let conflicted = merged_main_rs.is_err();
+            fs::write(&main_rs_path, merged_main_rs.unwrap_or_else(|conflicted_main_rs| conflicted_main_rs))?;
+            main_rs_regeneration::store_last_rendered(&project_path, &main_rs_contents)?;
+            if conflicted {
+                println!("{}", "  Some changes overlapped, so look for the <<<<<<< conflict markers in main.rs".yellow());
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Write out a freshly rendered main.rs, keeping a copy of it to tell later edits apart from the template
+fn write_rendered_main_rs(project_path: &Path, main_rs_path: &Path, main_rs_contents: &str) -> error::Result<()> {
+    fs::write(main_rs_path, main_rs_contents)?;
+    main_rs_regeneration::store_last_rendered(project_path, main_rs_contents)?;
+    Ok(())
+}
+
+fn plan_main_rs(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    let project_path = scaffolder.project_dir(day_info);
+    let main_rs_path = project_path.join("src").join("main.rs");
+    let last_rendered = main_rs_regeneration::read_last_rendered(&project_path);
+
+    let planned_file = match fs::read_to_string(&main_rs_path) {
+        Err(_) => PlannedFile::new(main_rs_path, FileAction::Create),
+        Ok(existing_main_rs) if main_rs_regeneration::is_safe_to_overwrite(&existing_main_rs, last_rendered.as_deref()) => {
+            PlannedFile::new(main_rs_path, FileAction::Overwrite)
+        }
+        Ok(existing_main_rs) if existing_main_rs.contains("fn part_one_example_test()") && !day_info.regenerate_main => {
+            PlannedFile::new(main_rs_path, FileAction::Update)
+        }
+        Ok(_) => match day_info.on_conflict {
+            Some(ConflictChoice::Overwrite) => PlannedFile::new(main_rs_path, FileAction::Overwrite),
+            Some(ConflictChoice::New) => PlannedFile::create_if_missing(main_rs_path.with_extension("rs.new")),
+            Some(ConflictChoice::Merge) => PlannedFile::new(main_rs_path, FileAction::Update),
+            None => PlannedFile::new(main_rs_path, FileAction::Ask),
+        },
+    };
+    Ok(vec![planned_file])
+}
+
+/// The template files that aren't handled by a step of their own (like `lib.rs` or `bench.rs`) paired up with where they go
+fn extra_template_files<'a>(scaffolder: &Scaffolder, day_info: &'a DayInfo) -> Vec<(&'a str, PathBuf)> {
+    let project_path = scaffolder.project_dir(day_info);
+    day_info
+        .templates
+        .names()
+        .into_iter()
+        .filter(|name| !["main.rs", "README.md"].contains(name))
+        .map(|name| (name, project_path.join(templates::destination(name))))
+        .collect()
+}
+
+/// Render the rest of the flavour's files into the project, leaving any that are already there alone
+fn render_template_files(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    let extra_files = extra_template_files(scaffolder, day_info);
+    if extra_files.is_empty() {
+        println!("  No other template files in this flavour");
+        return Ok(());
+    }
+
+    let expected_answers = expected_answers::find_expected_answers(&day_info.day_html);
+    let context = template_context(day_info, &expected_answers);
+    for (name, file_path) in extra_files {
+        if file_path.exists() {
+            print_already_exists(&file_path);
+            continue;
+        }
+        let contents = day_info.templates.render(name, &context)?;
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&file_path, contents)?;
+        print_created(&file_path);
+    }
+
+    Ok(())
+}
+
+fn plan_template_files(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    Ok(extra_template_files(scaffolder, day_info)
+        .into_iter()
+        .map(|(_, file_path)| PlannedFile::create_if_missing(file_path))
+        .collect())
+}
+
+/// Once part two is unlocked, add its description to the README and its example answer to the part two example test in
+///  main.rs. Anything written by hand is left as it is
+fn update_for_part_two(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    let Some(part_two_markdown) = markdown::part_two_description(&day_info.day_html, markdown::site_url(&day_info.day_url)) else {
+        println!("{}", "  Part two isn't on the puzzle page yet (it shows up once part one is solved), so there's nothing to update".yellow());
+        return Ok(());
+    };
+    let project_path = scaffolder.project_dir(day_info);
+
+    // Add the description to the end of the README, unless it's been added already
+    let readme_path = project_path.join("README.md");
+    match readme_with_part_two(&readme_path, &part_two_markdown) {
+        Some(readme_contents) => {
+            fs::write(&readme_path, readme_contents)?;
+            println!("{}", format!("  Added part two to {}!", get_file_bg_string(&readme_path)).green());
+        }
+        None => println!("{}", format!("  {} already has part two in it, skipping...", get_file_bg_string(&readme_path)).yellow()),
+    }
+
+    // Fill in the answer to part two's example where the template left a placeholder for it
+    let main_rs_path = project_path.join("src").join("main.rs");
+    match main_rs_with_part_two_answer(day_info, &main_rs_path)? {
+        Some(main_rs_contents) => {
+            fs::write(&main_rs_path, main_rs_contents)?;
+            println!("{}", format!("  Filled in part two's example answer in {}!", get_file_bg_string(&main_rs_path)).green());
+        }
+        None => println!(
+            "{}",
+            format!("  No placeholder (or no answer) for part two's example in {}, leaving it alone", get_file_bg_string(&main_rs_path)).yellow()
+        ),
+    }
+
+    Ok(())
+}
+
+/// The README with part two's description added to it, or None if it's already there
+fn readme_with_part_two(readme_path: &Path, part_two_markdown: &str) -> Option<String> {
+    let readme_contents = fs::read_to_string(readme_path).unwrap_or_default();
+    let heading = part_two_markdown.lines().next().unwrap_or_default();
+    if readme_contents.lines().any(|line| line == heading) {
+        return None;
+    }
+    Some(format!("{}\n\n{part_two_markdown}\n", readme_contents.trim_end()).trim_start().to_string())
+}
+
+/// main.rs with the answer to part two's example filled in, or None if there's nothing to fill in
+fn main_rs_with_part_two_answer(day_info: &DayInfo, main_rs_path: &Path) -> error::Result<Option<String>> {
+    let Ok(main_rs_contents) = fs::read_to_string(main_rs_path) else {
+        return Ok(None);
+    };
+    let part_two_answer = expected_answers::ExpectedAnswers {
+        part_one: None,
+        part_two: expected_answers::find_expected_answers(&day_info.day_html).part_two,
+    };
+    let filled_in_main_rs = expected_answers::fill_in_expected_answers(&main_rs_contents, &part_two_answer);
+    Ok((filled_in_main_rs != main_rs_contents).then_some(filled_in_main_rs))
+}
+
+fn plan_update_for_part_two(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    let Some(part_two_markdown) = markdown::part_two_description(&day_info.day_html, markdown::site_url(&day_info.day_url)) else {
+        return Ok(Vec::new());
+    };
+    let project_path = scaffolder.project_dir(day_info);
+    let readme_path = project_path.join("README.md");
+    let main_rs_path = project_path.join("src").join("main.rs");
+
+    let readme_action = match (readme_path.exists(), readme_with_part_two(&readme_path, &part_two_markdown)) {
+        (_, None) => FileAction::Skip,
+        (true, Some(_)) => FileAction::Update,
+        (false, Some(_)) => FileAction::Create,
+    };
+    let main_rs_action = match main_rs_with_part_two_answer(day_info, &main_rs_path)? {
+        Some(_) => FileAction::Update,
+        None => FileAction::Skip,
+    };
+    Ok(vec![PlannedFile::new(readme_path, readme_action), PlannedFile::new(main_rs_path, main_rs_action)])
+}
+
+/// Add the project as a member of the workspace in the root `Cargo.toml`, creating the workspace if there isn't one yet
+fn register_workspace_member(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    let root_manifest_path = scaffolder.in_root("Cargo.toml");
+    match workspace::register_member(&root_manifest_path, &day_info.project_path)? {
+        MemberRegistration::CreatedWorkspace => {
+            println!("{}", format!("  Created a workspace in {} with '{}' in it!", get_file_bg_string(&root_manifest_path), day_info.project_path).green())
+        }
+        MemberRegistration::AddedMember => {
+            println!("{}", format!("  Added '{}' to the workspace in {}!", day_info.project_path, get_file_bg_string(&root_manifest_path)).green())
+        }
+        MemberRegistration::AlreadyMember => {
+            println!("{}", format!("  '{}' is already in the workspace, skipping...", day_info.project_path).yellow())
+        }
+    }
+    Ok(())
+}
+
+fn plan_workspace_member(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    let root_manifest_path = scaffolder.in_root("Cargo.toml");
+    let action = match (root_manifest_path.exists(), workspace::is_member(&root_manifest_path, &day_info.project_path)) {
+        (false, _) => FileAction::Create,
+        (true, false) => FileAction::Update,
+        (true, true) => FileAction::Skip,
+    };
+    Ok(vec![PlannedFile::new(root_manifest_path, action)])
+}
+
+/// Keep an encrypted copy of the puzzle input next to it, which is safe to commit (unlike the input itself)
+#[cfg(feature = "encryption")]
+fn encrypt_question_input(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    let input_path = scaffolder.project_dir(day_info).join("inputs").join(&day_info.input_files.question);
+    if !has_content(&input_path) {
+        println!("{}", format!("  {} doesn't have the puzzle input in it yet, so there's nothing to encrypt", get_file_bg_string(&input_path)).yellow());
+        return Ok(());
+    }
+
+    let key = input_encryption::InputKey::load_or_create(&input_key_path(day_info.key_file.as_deref())?)?;
+    let encrypted_path = input_encryption::encrypted_path(&input_path);
+    match input_encryption::encrypt_input(&key, &input_path)? {
+        true => print_created(&encrypted_path),
+        false => println!("{}", format!("  {} is already up to date, skipping...", get_file_bg_string(&encrypted_path)).yellow()),
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "encryption"))]
+fn encrypt_question_input(_scaffolder: &Scaffolder, _day_info: &DayInfo) -> error::Result<()> {
+    Err(encryption_not_built())
+}
+
+#[cfg(feature = "encryption")]
+fn plan_encrypt_question_input(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    let input_path = scaffolder.project_dir(day_info).join("inputs").join(&day_info.input_files.question);
+    let encrypted_path = input_encryption::encrypted_path(&input_path);
+    let action = if encrypted_path.exists() { FileAction::Update } else { FileAction::Create };
+    Ok(vec![PlannedFile::new(encrypted_path, action)])
+}
+
+#[cfg(not(feature = "encryption"))]
+fn plan_encrypt_question_input(_scaffolder: &Scaffolder, _day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    Err(encryption_not_built())
+}
+
+/// Switch to a `dayN` branch (creating it unless it's already checked out) and commit the project on it, leaving out the
+///  private files like the puzzle input
+fn commit_to_day_branch(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<()> {
+    let (runner, repo_dir) = (scaffolder.runner.as_ref(), scaffolder.root.as_path());
+    let branch = format!("day{}", day_info.day_num);
+    if git::current_branch(runner, repo_dir)? == branch {
+        println!("{}", format!("  Already on the '{branch}' branch").yellow());
+    }
+    else {
+        git::create_branch(runner, repo_dir, &branch)?;
+        println!("  Switched to a new '{branch}' branch");
+    }
+
+    git::stage(runner, repo_dir, &day_info.project_path, &day_info.private_files)?;
+    let title = day_info.day_title.trim_start_matches("Advent of Code - ");
+    match git::commit(runner, repo_dir, &format!("Template out {title}"))? {
+        true => println!("{}", format!("  Committed {}!", get_file_bg_string(&scaffolder.project_dir(day_info))).green()),
+        false => println!("{}", "  Nothing new in the project to commit, skipping...".yellow()),
+    }
+    Ok(())
+}
+
+fn plan_commit_to_day_branch(scaffolder: &Scaffolder, day_info: &DayInfo) -> error::Result<Vec<PlannedFile>> {
+    if !git::is_clean(scaffolder.runner.as_ref(), &scaffolder.root)? {
+        println!("{}", "  The working tree has changes in it, so this step would refuse to run".yellow());
+    }
+    Ok(vec![PlannedFile::new(scaffolder.project_dir(day_info), FileAction::Commit(format!("day{}", day_info.day_num)))])
+}
+
+// endregion
+
+// region: Tests
+
+#[cfg(test)]
+use std::collections::HashMap;
+
+#[cfg(test)]
+use crate::{aoc_client::{AocClient, HttpClient}, command_runner::FakeRunner, test_server};
+
+/// Stands in for the site, serving the pages at the urls it's given
+#[cfg(test)]
+struct FakeHttp(HashMap<String, String>);
+
+#[cfg(test)]
+impl HttpClient for FakeHttp {
+    fn get(&self, url: &str, _headers: &[(&str, String)]) -> error::Result<String> {
+        self.0
+            .get(url)
+            .cloned()
+            .ok_or_else(|| AdventemplateError::Input(format!("Nothing at {url}")))
+    }
+
+    fn post_form(&self, url: &str, _headers: &[(&str, String)], _form: &[(&str, &str)]) -> error::Result<String> {
+        Err(AdventemplateError::Input(format!("Nothing to post to at {url}")))
+    }
+}
+
+#[cfg(test)]
+fn test_day_info() -> DayInfo {
+    DayInfo {
+        year: String::from("2022"),
+        day_num: 1,
+        project_name: String::from("day1-calorie-counting"),
+        project_path: String::from("day1-calorie-counting"),
+        day_title: String::from("Advent of Code - Day 1: Calorie Counting"),
+        day_url: String::from("https://adventofcode.com/2022/day/1"),
+        input_structure_name: String::from("calorie_list"),
+        day_html: String::from(include_str!("../fixtures/day1.html")),
+        example_index: None,
+        regenerate_main: false,
+        on_conflict: None,
+        templates: TemplateSet::load(None, templates::DEFAULT_FLAVOUR).unwrap(),
+        input_files: InputFiles::default(),
+        gitignore: Config::default().gitignore,
+        private_files: Config::default().private_files,
+        key_file: None,
+    }
+}
+
+#[cfg(test)]
+fn test_puzzle_source(base_url: String, cache_dir: &Path) -> PuzzleSource {
+    PuzzleSource {
+        client: AocClient::new(base_url, Some(String::from("token"))),
+        cache_dir: cache_dir.to_path_buf(),
+        offline: false,
+        read_only: false,
+    }
+}
+
+/// A scaffolder for the root that gets day 6 from a stand-in site and runs commands through the fake runner
+#[cfg(test)]
+fn test_scaffolder(root: &Path, runner: &FakeRunner) -> Scaffolder {
+    let pages = [
+        ("https://adventofcode.com/2022/day/6", include_str!("../fixtures/day6.html")),
+        ("https://adventofcode.com/2022/day/6/input", "my puzzle input\n"),
+    ];
+    let mut puzzle_source = test_puzzle_source(String::from(aoc_client::DEFAULT_BASE_URL), &root.join("cache"));
+    puzzle_source.client.http = Box::new(FakeHttp(pages.into_iter().map(|(url, page)| (url.to_string(), page.to_string())).collect()));
+    Scaffolder {
+        runner: Box::new(runner.clone()),
+        ..Scaffolder::new(root, puzzle_source)
+    }
+}
+
+#[cfg(test)]
+fn test_day6_info(scaffolder: &Scaffolder) -> DayInfo {
+    DayInfo {
+        templates: TemplateSet::load(None, templates::DEFAULT_FLAVOUR).unwrap(),
+        ..scaffolder.look_up_day("2022", 6, ProjectLayout::Flat, Some(String::from("buffer")), true).unwrap()
+    }
+}
+
+#[test]
+fn default_steps_set_up_a_day_from_scratch() {
+    let root = tempfile::tempdir().unwrap();
+    let runner = FakeRunner::default();
+    let scaffolder = test_scaffolder(root.path(), &runner);
+    let day_info = test_day6_info(&scaffolder);
+
+    let step_results = scaffolder.run(&day_info, select_steps(&[], &[], &[], None).unwrap());
+
+    assert_eq!(step_results.iter().map(|(name, _)| *name).collect::<Vec<_>>(), ["cargo", "gitignore", "readme", "inputs", "examples", "main", "files"]);
+    assert!(step_results.iter().all(|(_, step_result)| *step_result == StepResult::Succeeded));
+    assert_eq!(runner.commands(), ["cargo new day6-tuning-trouble --name day6-tuning-trouble"]);
+    assert_eq!(runner.commands.borrow()[0].1, root.path());
+
+    let project_dir = root.path().join("day6-tuning-trouble");
+    let read = |file_path: &str| fs::read_to_string(project_dir.join(file_path)).unwrap();
+    assert_eq!(read(".gitignore"), "/target\n/.adventemplate\n/inputs/question\n");
+    assert!(read("README.md").starts_with("# Advent of Code - Day 6: Tuning Trouble"));
+    assert_eq!(read("inputs/example"), "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+    assert_eq!(read("inputs/question"), "my puzzle input\n");
+    assert!(read("src/main.rs").contains("let question_buffer = include_str!(\"../inputs/question\").trim();"));
+}
+
+#[test]
+fn dry_run_plans_the_steps_without_touching_anything() {
+    let root = tempfile::tempdir().unwrap();
+    let runner = FakeRunner::default();
+    let scaffolder = test_scaffolder(root.path(), &runner);
+    let day_info = test_day6_info(&scaffolder);
+
+    let step_plans = scaffolder.plan(&day_info, select_steps(&[], &[], &[String::from("workspace")], None).unwrap());
+
+    let project_dir = root.path().join("day6-tuning-trouble");
+    assert_eq!(step_plans[0].0, "cargo");
+    assert_eq!(
+        step_plans[0].1.as_ref().unwrap(),
+        &[
+            PlannedFile::new(project_dir.join("Cargo.toml"), FileAction::Create),
+            PlannedFile::new(project_dir.join("src").join("main.rs"), FileAction::Create),
+        ]
+    );
+    let (last_step, last_plan) = step_plans.last().unwrap();
+    assert_eq!(*last_step, "workspace");
+    assert_eq!(last_plan.as_ref().unwrap(), &[PlannedFile::new(root.path().join("Cargo.toml"), FileAction::Create)]);
+    assert!(runner.commands().is_empty());
+    assert!(!project_dir.exists());
+    assert!(!root.path().join("Cargo.toml").exists());
+}
+
+#[test]
+fn failing_cargo_new_stops_the_rest_of_the_steps() {
+    let root = tempfile::tempdir().unwrap();
+    let runner = FakeRunner { failing: Some("cargo new"), ..Default::default() };
+    let scaffolder = test_scaffolder(root.path(), &runner);
+    let day_info = test_day6_info(&scaffolder);
+
+    let step_results = scaffolder.run(&day_info, select_steps(&[], &[String::from("files")], &[], None).unwrap());
+
+    assert_eq!(
+        step_results,
+        [
+            ("cargo", StepResult::Failed(ErrorCategory::Cargo)),
+            ("gitignore", StepResult::NotRun),
+            ("readme", StepResult::NotRun),
+            ("inputs", StepResult::NotRun),
+            ("examples", StepResult::NotRun),
+            ("main", StepResult::NotRun),
+        ]
+    );
+    assert!(!root.path().join("day6-tuning-trouble").exists());
+}
+
+#[test]
+fn workspace_and_git_steps_register_and_commit_the_day() {
+    let root = tempfile::tempdir().unwrap();
+    let runner = FakeRunner::default();
+    let scaffolder = test_scaffolder(root.path(), &runner);
+    let day_info = test_day6_info(&scaffolder);
+    let with = [String::from("workspace"), String::from("git")];
+
+    scaffolder.ensure_clean().unwrap();
+    let step_results = scaffolder.run(&day_info, select_steps(&[], &[], &with, None).unwrap());
+
+    assert!(step_results.iter().all(|(_, step_result)| *step_result == StepResult::Succeeded));
+    assert!(workspace::is_member(&root.path().join("Cargo.toml"), "day6-tuning-trouble"));
+    assert_eq!(
+        runner.commands(),
+        [
+            "git status --porcelain",
+            "cargo new day6-tuning-trouble --name day6-tuning-trouble",
+            "git branch --show-current",
+            "git rev-parse --verify --quiet refs/heads/day6",
+            "git checkout -b day6",
+            "git add -- day6-tuning-trouble :(exclude)day6-tuning-trouble/inputs/question",
+            "git diff --cached --quiet",
+            "git commit --quiet -m Template out Day 6: Tuning Trouble",
+        ]
+    );
+    assert!(runner.commands.borrow().iter().all(|(_, dir)| dir == root.path()));
+}
+
+#[test]
+fn unknown_default_steps_are_an_input_error() {
+    let default_steps = [String::from("cargo"), String::from("deploy")];
+
+    assert!(matches!(select_steps(&[], &[], &[], Some(&default_steps)), Err(AdventemplateError::Input(_))));
+}
+
+#[test]
+fn question_input_is_downloaded_into_an_empty_file() {
+    let server = test_server::TestServer::start([("/2022/day/1/input", (200, "1000\n2000\n"))]);
+    let temp_dir = tempfile::tempdir().unwrap();
+    let question_path = temp_dir.path().join("question");
+    File::create(&question_path).unwrap();
+    let scaffolder = Scaffolder::new(temp_dir.path(), test_puzzle_source(server.base_url(), temp_dir.path()));
+
+    download_question_input(&scaffolder, &test_day_info(), &question_path).unwrap();
+
+    assert_eq!(fs::read_to_string(&question_path).unwrap(), "1000\n2000\n");
+}
+
+#[test]
+fn question_input_with_content_is_not_downloaded_again() {
+    let server = test_server::TestServer::start([("/2022/day/1/input", (200, "1000\n2000\n"))]);
+    let temp_dir = tempfile::tempdir().unwrap();
+    let question_path = temp_dir.path().join("question");
+    fs::write(&question_path, "my own input\n").unwrap();
+    let scaffolder = Scaffolder::new(temp_dir.path(), test_puzzle_source(server.base_url(), temp_dir.path()));
+
+    download_question_input(&scaffolder, &test_day_info(), &question_path).unwrap();
+
+    assert_eq!(fs::read_to_string(&question_path).unwrap(), "my own input\n");
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn update_adds_part_two_and_leaves_the_rest_alone() {
+    let root = tempfile::tempdir().unwrap();
+    let scaffolder = Scaffolder::new(root.path(), test_puzzle_source(String::new(), root.path()));
+    let day_info = DayInfo {
+        day_num: 6,
+        project_path: String::from("day6-tuning-trouble"),
+        day_html: String::from(include_str!("../fixtures/day6.html")),
+        ..test_day_info()
+    };
+    let project_dir = root.path().join("day6-tuning-trouble");
+    fs::create_dir_all(project_dir.join("src")).unwrap();
+    let readme_path = project_dir.join("README.md");
+    let main_rs_path = project_dir.join("src").join("main.rs");
+    fs::write(&readme_path, "# Advent of Code - Day 6: Tuning Trouble\nMy notes\n").unwrap();
+    let main_rs = "\
+fn part_one(buffer: &str) -> usize { buffer.len() }
+
+#[test]
+fn part_one_example_test() {
+    assert_eq!(part_one_result, 0);
+}
+
+#[test]
+fn part_two_example_test() {
+    assert_eq!(part_two_result, 0);
+}
+";
+    fs::write(&main_rs_path, main_rs).unwrap();
+
+    update_for_part_two(&scaffolder, &day_info).unwrap();
+    update_for_part_two(&scaffolder, &day_info).unwrap();
+
+    let readme = fs::read_to_string(&readme_path).unwrap();
+    assert!(readme.starts_with("# Advent of Code - Day 6: Tuning Trouble\nMy notes\n\n## Part Two\n\nYour device's"));
+    assert_eq!(readme.matches("## Part Two").count(), 1);
+    assert_eq!(
+        fs::read_to_string(&main_rs_path).unwrap(),
+        main_rs.replace("assert_eq!(part_two_result, 0);", "assert_eq!(part_two_result, 19);")
+    );
+}
+
+#[test]
+fn missing_example_block_is_an_input_error() {
+    let scaffolder = Scaffolder::new(".", test_puzzle_source(String::new(), Path::new("cache")));
+    let day_info = DayInfo { example_index: Some(42), ..test_day_info() };
+
+    let error = find_example_inputs(&scaffolder, &day_info).unwrap_err();
+
+    assert_eq!(error.category(), ErrorCategory::Input);
+    assert_eq!(error.category().exit_code(), 3);
+}
+
+// endregion
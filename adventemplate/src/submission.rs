@@ -0,0 +1,400 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use scraper::{Html, Selector};
+
+use crate::{
+    aoc_client::AocClient,
+    command_runner::CommandRunner,
+    error::{AdventemplateError, Result},
+};
+
+/// What the site said about a submitted answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was given too recently, with how many seconds are left to wait (when the site said)
+    Wait(Option<u64>),
+    /// The part was already solved (or isn't unlocked yet)
+    WrongLevel,
+    /// Anything else, with the text of the response
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Whether the answer is known not to be the right one, so sending it again would be pointless
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::Wrong => write!(f, "That's not the right answer"),
+            Outcome::TooHigh => write!(f, "That's not the right answer, it's too high"),
+            Outcome::TooLow => write!(f, "That's not the right answer, it's too low"),
+            Outcome::Wait(Some(seconds)) => write!(f, "An answer was given too recently, {seconds}s left to wait"),
+            Outcome::Wait(None) => write!(f, "An answer was given too recently, wait a bit before trying again"),
+            Outcome::WrongLevel => write!(f, "This part isn't the one to solve (is it already solved?)"),
+            Outcome::Unknown(text) => write!(f, "Not sure how that went: {text}"),
+        }
+    }
+}
+
+/// Work out the outcome from the page the site sends back after posting an answer
+pub fn parse_response(response_html: &str) -> Outcome {
+    let response_document = Html::parse_document(response_html);
+    let article_selector = Selector::parse("article").unwrap();
+    let text = response_document
+        .select(&article_selector)
+        .next()
+        .map(|article| article.text().collect::<String>())
+        .unwrap_or_else(|| response_document.root_element().text().collect());
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    }
+    else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        }
+        else if text.contains("too low") {
+            Outcome::TooLow
+        }
+        else {
+            Outcome::Wrong
+        }
+    }
+    else if text.contains("You gave an answer too recently") {
+        Outcome::Wait(parse_wait_seconds(&text))
+    }
+    else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    }
+    else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// Pull the time left out of e.g. "You have 1m 30s left to wait"
+fn parse_wait_seconds(text: &str) -> Option<u64> {
+    let before_left = &text[..text.find(" left to wait")?];
+    let time_left = &before_left[(before_left.rfind("You have ")? + "You have ".len())..];
+    time_left.split_whitespace().try_fold(0, |seconds, amount| {
+        let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let number = number.parse::<u64>().ok()?;
+        match unit {
+            "h" => Some(seconds + number * 3600),
+            "m" => Some(seconds + number * 60),
+            "s" => Some(seconds + number),
+            _ => None,
+        }
+    })
+}
+
+/// Find the answer for the part in the output of a day's solution, which prints e.g. "Part One:\n  The result is: 24000"
+///  (the answer can also be on the same line, as in "Part One: 24000"). A description ending in a colon, like day 10's
+///  "The output of the CRT screen is:", is followed by an answer spread over the lines up to the next part
+pub fn scrape_answer(output: &str, part: u8) -> Option<String> {
+    let label = match part {
+        1 => "Part One:",
+        _ => "Part Two:",
+    };
+    let mut lines = output.lines().skip_while(|line| !line.trim_start().starts_with(label));
+    let label_line = lines.next()?;
+
+    let same_line = label_line.trim_start()[label.len()..].trim();
+    let answer_line = if same_line.is_empty() { lines.next()? } else { same_line };
+    if answer_line.trim_end().ends_with(':') {
+        let answer_lines = lines
+            .take_while(|line| !line.trim_start().starts_with("Part "))
+            .collect::<Vec<_>>();
+        return (!answer_lines.is_empty()).then(|| answer_lines.join("\n").trim_end().to_string());
+    }
+    let answer = answer_line.rsplit(": ").next()?.trim();
+    (!answer.is_empty()).then(|| answer.to_string())
+}
+
+/// Build and run the day's project in release mode, returning what it printed
+pub fn run_project(runner: &dyn CommandRunner, project_path: &Path) -> Result<String> {
+    let output = runner
+        .run("cargo", &["run", "--release", "--quiet"], project_path)
+        .map_err(|e| AdventemplateError::Cargo(format!("Failed to run 'cargo run': {e}")))?;
+    if !output.success {
+        return Err(AdventemplateError::Cargo(format!("'cargo run' failed ({}):\n{}", output.status, output.stderr)));
+    }
+    Ok(output.stdout)
+}
+
+/// One answer that was sent, as kept in the history file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    /// When it was sent, in seconds since the unix epoch
+    pub timestamp: u64,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Every answer sent for a day, kept in the project so known-wrong answers can be caught before they're sent
+pub struct SubmissionHistory {
+    path: PathBuf,
+    pub attempts: Vec<Attempt>,
+}
+
+impl SubmissionHistory {
+    pub fn path(project_path: &Path) -> PathBuf {
+        project_path.join(".adventemplate").join("submissions")
+    }
+
+    /// Read the history for the project, which is empty if nothing has been submitted yet
+    pub fn load(project_path: &Path) -> Result<Self> {
+        let path = Self::path(project_path);
+        let attempts = match fs::read_to_string(&path) {
+            Ok(history) => history.lines().filter_map(parse_attempt).collect(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(SubmissionHistory { path, attempts })
+    }
+
+    /// Why the answer shouldn't be sent, if there's something in the history that says so
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> std::result::Result<(), String> {
+        let part_attempts = self.attempts.iter().filter(|attempt| attempt.part == part).collect::<Vec<_>>();
+
+        if let Some(correct) = part_attempts.iter().find(|attempt| attempt.outcome == Outcome::Correct) {
+            return Err(format!("Part {part} was already solved with {}", correct.answer));
+        }
+        if let Some(wrong) = part_attempts.iter().find(|attempt| attempt.answer == answer && attempt.outcome.is_wrong()) {
+            return Err(format!("{answer} was already tried for part {part}: {}", wrong.outcome));
+        }
+
+        // Numbers past an answer that was too high (or too low) are just as wrong
+        if let Ok(number) = answer.parse::<i64>() {
+            for attempt in &part_attempts {
+                let Ok(attempted) = attempt.answer.parse::<i64>() else {
+                    continue;
+                };
+                match attempt.outcome {
+                    Outcome::TooHigh if number >= attempted => {
+                        return Err(format!("{answer} can't be right, {attempted} was already too high"))
+                    }
+                    Outcome::TooLow if number <= attempted => {
+                        return Err(format!("{answer} can't be right, {attempted} was already too low"))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Don't bother the site again until it's done asking to wait
+        if let Some(wait_until) = self.wait_until() {
+            if now < wait_until {
+                return Err(format!("The site asked to wait before answering again, {}s left", wait_until - now));
+            }
+        }
+        Ok(())
+    }
+
+    /// When the last request to wait runs out
+    fn wait_until(&self) -> Option<u64> {
+        match self.attempts.last()? {
+            Attempt { timestamp, outcome: Outcome::Wait(seconds), .. } => Some(timestamp + seconds.unwrap_or(60)),
+            _ => None,
+        }
+    }
+
+    /// Add the attempt to the history, writing it out straight away
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        self.attempts.push(attempt);
+        let history = self.attempts.iter().map(format_attempt).collect::<String>();
+        fs::write(&self.path, history)?;
+        Ok(())
+    }
+}
+
+/// Each attempt is a tab separated line: timestamp, part, outcome, and answer
+fn format_attempt(attempt: &Attempt) -> String {
+    let outcome = match &attempt.outcome {
+        Outcome::Correct => String::from("correct"),
+        Outcome::Wrong => String::from("wrong"),
+        Outcome::TooHigh => String::from("too-high"),
+        Outcome::TooLow => String::from("too-low"),
+        Outcome::Wait(Some(seconds)) => format!("wait-{seconds}"),
+        Outcome::Wait(None) => String::from("wait"),
+        Outcome::WrongLevel => String::from("wrong-level"),
+        Outcome::Unknown(_) => String::from("unknown"),
+    };
+    format!("{}\t{}\t{outcome}\t{}\n", attempt.timestamp, attempt.part, attempt.answer)
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.splitn(4, '\t');
+    let timestamp = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let outcome = match fields.next()? {
+        "correct" => Outcome::Correct,
+        "wrong" => Outcome::Wrong,
+        "too-high" => Outcome::TooHigh,
+        "too-low" => Outcome::TooLow,
+        "wait" => Outcome::Wait(None),
+        "wrong-level" => Outcome::WrongLevel,
+        outcome => match outcome.strip_prefix("wait-") {
+            Some(seconds) => Outcome::Wait(seconds.parse().ok()),
+            None => Outcome::Unknown(outcome.to_string()),
+        },
+    };
+    let answer = fields.next()?.to_string();
+    Some(Attempt { timestamp, part, answer, outcome })
+}
+
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since_epoch| since_epoch.as_secs())
+}
+
+/// Send the answer for the part, unless the history already says it won't be right, and record how it went
+pub fn submit(client: &AocClient, year: &str, day_num: u8, part: u8, answer: &str, history: &mut SubmissionHistory) -> Result<Outcome> {
+    if client.session.is_none() {
+        return Err(AdventemplateError::Input(String::from("A session token is needed to submit answers")));
+    }
+    if answer.contains('\n') {
+        return Err(AdventemplateError::Input(format!("The answer has to be on one line, not:\n{answer}")));
+    }
+    let now = now();
+    history.check(part, answer, now).map_err(AdventemplateError::Input)?;
+
+    let response_html = client.post_form(&format!("/{year}/day/{day_num}/answer"), &[("level", &part.to_string()), ("answer", answer)])?;
+    let outcome = parse_response(&response_html);
+    history.record(Attempt {
+        timestamp: now,
+        part,
+        answer: answer.to_string(),
+        outcome: outcome.clone(),
+    })?;
+    Ok(outcome)
+}
+
+// region: Tests
+
+#[cfg(test)]
+use crate::{command_runner::FakeRunner, test_server::TestServer};
+
+#[cfg(test)]
+fn response_page(text: &str) -> String {
+    format!("<html><body><main>\n<article><p>{text}</p></article>\n</main></body></html>")
+}
+
+#[test]
+fn responses_are_parsed_into_outcomes() {
+    assert_eq!(
+        parse_response(&response_page("That's the right answer!  You are <em>one gold star</em> closer to collecting enough star fruit.")),
+        Outcome::Correct
+    );
+    assert_eq!(
+        parse_response(&response_page("That's not the right answer; your answer is too high.  If you're stuck, make sure...")),
+        Outcome::TooHigh
+    );
+    assert_eq!(parse_response(&response_page("That's not the right answer; your answer is too low.")), Outcome::TooLow);
+    assert_eq!(parse_response(&response_page("That's not the right answer.  If you're stuck, ...")), Outcome::Wrong);
+    assert_eq!(
+        parse_response(&response_page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.")),
+        Outcome::Wait(Some(65))
+    );
+    assert_eq!(
+        parse_response(&response_page("You don't seem to be solving the right level.  Did you already complete it?")),
+        Outcome::WrongLevel
+    );
+}
+
+#[test]
+fn answers_are_scraped_from_the_solution_output() {
+    let output = "Part One:\n  The result is: 24000\nPart Two:\n  The result is: 45000\n";
+
+    assert_eq!(scrape_answer(output, 1), Some(String::from("24000")));
+    assert_eq!(scrape_answer(output, 2), Some(String::from("45000")));
+    assert_eq!(scrape_answer("Part One: CMZ\n", 1), Some(String::from("CMZ")));
+    assert_eq!(scrape_answer("Part One: CMZ\n", 2), None);
+    assert_eq!(
+        scrape_answer("Part Two:\n  The output of the CRT screen is:\n##..\n#..#\n", 2),
+        Some(String::from("##..\n#..#"))
+    );
+}
+
+#[test]
+fn answers_come_from_running_the_project() {
+    let project_dir = tempfile::tempdir().unwrap();
+    let runner = FakeRunner {
+        outputs: vec![("cargo run", String::from("Part One:\n  The result is: 24000\n"))],
+        ..Default::default()
+    };
+
+    let output = run_project(&runner, project_dir.path()).unwrap();
+
+    assert_eq!(runner.commands(), ["cargo run --release --quiet"]);
+    assert_eq!(runner.commands.borrow()[0].1, project_dir.path());
+    assert_eq!(scrape_answer(&output, 1), Some(String::from("24000")));
+
+    let failure = run_project(&FakeRunner { failing: Some("cargo run"), ..Default::default() }, project_dir.path());
+    assert!(matches!(failure, Err(AdventemplateError::Cargo(message)) if message.ends_with("error: it broke")));
+}
+
+#[test]
+fn submission_is_posted_and_recorded() {
+    let server = TestServer::start([(
+        "/2022/day/1/answer",
+        (200, "<html><body><main><article><p>That's not the right answer; your answer is too low.</p></article></main></body></html>"),
+    )]);
+    let client = AocClient::new(server.base_url(), Some(String::from("token")));
+    let project_dir = tempfile::tempdir().unwrap();
+    let mut history = SubmissionHistory::load(project_dir.path()).unwrap();
+
+    let outcome = submit(&client, "2022", 1, 1, "1000", &mut history).unwrap();
+
+    assert_eq!(outcome, Outcome::TooLow);
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.body, "level=1&answer=1000");
+    assert_eq!(SubmissionHistory::load(project_dir.path()).unwrap().attempts, history.attempts);
+}
+
+#[test]
+fn known_wrong_answers_are_refused_before_sending() {
+    let server = TestServer::start([]);
+    let client = AocClient::new(server.base_url(), Some(String::from("token")));
+    let project_dir = tempfile::tempdir().unwrap();
+    let mut history = SubmissionHistory::load(project_dir.path()).unwrap();
+    for (answer, outcome) in [("1000", Outcome::TooLow), ("9000", Outcome::TooHigh), ("4242", Outcome::Wrong)] {
+        history.record(Attempt { timestamp: 0, part: 1, answer: answer.to_string(), outcome }).unwrap();
+    }
+
+    for answer in ["1000", "999", "9000", "12000", "4242"] {
+        assert!(matches!(submit(&client, "2022", 1, 1, answer, &mut history), Err(AdventemplateError::Input(_))), "{answer}");
+    }
+    assert!(server.requests().is_empty());
+    assert!(history.check(1, "5000", now()).is_ok());
+    assert!(history.check(2, "1000", now()).is_ok());
+}
+
+#[test]
+fn waits_are_respected() {
+    let project_dir = tempfile::tempdir().unwrap();
+    let mut history = SubmissionHistory::load(project_dir.path()).unwrap();
+    history.record(Attempt { timestamp: 1000, part: 1, answer: String::from("5000"), outcome: Outcome::Wait(Some(30)) }).unwrap();
+
+    assert!(history.check(1, "5000", 1010).is_err());
+    assert!(history.check(1, "5000", 1030).is_ok());
+}
+
+// endregion
@@ -0,0 +1,168 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use tera::{Context, Tera};
+
+use crate::error::{AdventemplateError, Result};
+
+/// The flavour used when none is asked for
+pub const DEFAULT_FLAVOUR: &str = "default";
+
+/// The flavours compiled into adventemplate. Every flavour starts from the default one's files, so the others only need the
+///  files they do differently
+const BUILT_IN_FLAVOURS: [(&str, &[(&str, &str)]); 4] = [
+    (
+        DEFAULT_FLAVOUR,
+        &[
+            ("main.rs", include_str!("../templates/default/main.rs")),
+            ("README.md", include_str!("../templates/default/README.md")),
+        ],
+    ),
+    ("grid", &[("main.rs", include_str!("../templates/grid/main.rs"))]),
+    ("parser-heavy", &[("main.rs", include_str!("../templates/parser-heavy/main.rs"))]),
+    ("simulation", &[("main.rs", include_str!("../templates/simulation/main.rs"))]),
+];
+
+/// The files of a template flavour, ready to be rendered into a day's project
+#[derive(Default)]
+pub struct TemplateSet {
+    tera: Tera,
+}
+
+impl TemplateSet {
+    /// Load the files of the flavour, built-in ones first and then those in `<template_dir>/<flavour>` (if there's a template
+    ///  directory) over the top of them. A flavour only has to exist in one of the two places
+    pub fn load(template_dir: Option<&Path>, flavour: &str) -> Result<Self> {
+        let built_in_flavour = BUILT_IN_FLAVOURS.iter().find(|(name, _)| *name == flavour);
+        let flavour_dir = template_dir.map(|template_dir| template_dir.join(flavour)).filter(|flavour_dir| flavour_dir.is_dir());
+        if built_in_flavour.is_none() && flavour_dir.is_none() {
+            let built_in_names = BUILT_IN_FLAVOURS.map(|(name, _)| name).join(", ");
+            return Err(AdventemplateError::Input(match template_dir {
+                Some(template_dir) => format!(
+                    "There's no '{flavour}' flavour built in ({built_in_names}) or in '{}'",
+                    template_dir.display()
+                ),
+                None => format!("There's no '{flavour}' flavour built in ({built_in_names})"),
+            }));
+        }
+
+        // Later files replace earlier ones with the same name
+        let mut files = Vec::new();
+        for (name, contents) in BUILT_IN_FLAVOURS[0].1.iter().chain(built_in_flavour.map_or(&[][..], |(_, files)| files)) {
+            add_file(&mut files, name.to_string(), contents.to_string());
+        }
+        if let Some(flavour_dir) = flavour_dir {
+            read_template_files(&flavour_dir, &flavour_dir, &mut files)?;
+        }
+
+        let mut tera = Tera::default();
+        tera.add_raw_templates(files)?;
+        Ok(TemplateSet { tera })
+    }
+
+    /// The names of the files in the set (their paths relative to the flavour, `/` separated)
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = self.tera.get_template_names().collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    pub fn render(&self, name: &str, context: &Context) -> Result<String> {
+        Ok(self.tera.render(name, context)?)
+    }
+}
+
+/// Where a template file goes in the project: Rust sources to `src`, benchmarks to `benches`, and anything else to the same
+///  place relative to the project as it has in the flavour
+pub fn destination(name: &str) -> PathBuf {
+    match name {
+        "main.rs" | "lib.rs" => Path::new("src").join(name),
+        "bench.rs" => Path::new("benches").join(name),
+        _ => name.split('/').collect(),
+    }
+}
+
+fn read_template_files(flavour_dir: &Path, dir: &Path, files: &mut Vec<(String, String)>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            read_template_files(flavour_dir, &path, files)?;
+            continue;
+        }
+
+        let name = path
+            .strip_prefix(flavour_dir)
+            .map_err(io::Error::other)?
+            .iter()
+            .map(|component| component.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        add_file(files, name, fs::read_to_string(&path)?);
+    }
+    Ok(())
+}
+
+fn add_file(files: &mut Vec<(String, String)>, name: String, contents: String) {
+    files.retain(|(existing_name, _)| *existing_name != name);
+    files.push((name, contents));
+}
+
+// region: Tests
+
+#[cfg(test)]
+fn test_context() -> Context {
+    let mut context = Context::new();
+    context.insert("input_structure_name", "heightmap");
+    context.insert("example_input", "example");
+    context.insert("question_input", "question");
+    context.insert("part_one_expected", "31");
+    context.insert("part_two_expected", "29");
+    context.insert("day_title", "Advent of Code - Day 12: Hill Climbing Algorithm");
+    context.insert("day_url", "https://adventofcode.com/2022/day/12");
+    context.insert("description", "## Day 12: Hill Climbing Algorithm\n\nYou try contacting the Elves...");
+    context
+}
+
+#[test]
+fn built_in_flavours_render() {
+    for (flavour, _) in BUILT_IN_FLAVOURS {
+        let template_set = TemplateSet::load(None, flavour).unwrap();
+        assert_eq!(template_set.names(), ["README.md", "main.rs"]);
+
+        let main_rs = template_set.render("main.rs", &test_context()).unwrap();
+        assert!(main_rs.contains("fn parse_heightmap(heightmap: &str)"), "{flavour}");
+        assert!(main_rs.contains("assert_eq!(part_one_result, 31);"), "{flavour}");
+    }
+}
+
+#[test]
+fn template_dir_files_go_over_the_built_in_ones() {
+    let template_dir = tempfile::tempdir().unwrap();
+    let grid_dir = template_dir.path().join("grid");
+    fs::create_dir_all(grid_dir.join("src")).unwrap();
+    fs::write(grid_dir.join("README.md"), "# {{ day_title }} (grid)\n").unwrap();
+    fs::write(grid_dir.join("bench.rs"), "// Benchmarks for {{ input_structure_name }}\n").unwrap();
+    fs::write(grid_dir.join("src").join("parsing.rs"), "").unwrap();
+
+    let template_set = TemplateSet::load(Some(template_dir.path()), "grid").unwrap();
+
+    assert_eq!(template_set.names(), ["README.md", "bench.rs", "main.rs", "src/parsing.rs"]);
+    assert_eq!(
+        template_set.render("README.md", &test_context()).unwrap(),
+        "# Advent of Code - Day 12: Hill Climbing Algorithm (grid)\n"
+    );
+    assert!(template_set.render("main.rs", &test_context()).unwrap().contains("struct Grid<T>"));
+    assert_eq!(destination("bench.rs"), Path::new("benches").join("bench.rs"));
+    assert_eq!(destination("src/parsing.rs"), Path::new("src").join("parsing.rs"));
+}
+
+#[test]
+fn unknown_flavours_are_an_input_error() {
+    let template_dir = tempfile::tempdir().unwrap();
+
+    assert!(matches!(TemplateSet::load(Some(template_dir.path()), "dynamic-programming"), Err(AdventemplateError::Input(_))));
+}
+
+// endregion
@@ -0,0 +1,96 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    thread::{self, JoinHandle},
+};
+
+use tiny_http::{Header, Response, Server};
+
+/// A request that the stand-in server received, kept around so tests can check what was sent
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A tiny local stand-in for adventofcode.com that serves canned responses keyed by path
+pub struct TestServer {
+    server: Arc<Server>,
+    handle: Option<JoinHandle<()>>,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl TestServer {
+    /// Start serving the given `(path, (status, body))` routes on a free local port (whatever the method). Unknown paths get a 404
+    pub fn start(routes: impl IntoIterator<Item = (&'static str, (u16, &'static str))>) -> Self {
+        let routes = routes
+            .into_iter()
+            .map(|(path, response)| (path.to_string(), response))
+            .collect::<HashMap<_, _>>();
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("Failed to start the test server"));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        // Answer requests on a background thread until the server is unblocked on drop
+        let handle = {
+            let server = Arc::clone(&server);
+            let requests = Arc::clone(&requests);
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let path = request.url().to_string();
+                    let mut body = String::new();
+                    request.as_reader().read_to_string(&mut body).ok();
+                    requests.lock().unwrap().push(RecordedRequest {
+                        method: request.method().to_string(),
+                        path: path.clone(),
+                        headers: request
+                            .headers()
+                            .iter()
+                            .map(|header| (header.field.to_string(), header.value.to_string()))
+                            .collect(),
+                        body,
+                    });
+
+                    let (status, body) = routes.get(&path).copied().unwrap_or((404, "Not found"));
+                    let response = Response::from_string(body)
+                        .with_status_code(status)
+                        .with_header(Header::from_bytes("Content-Type", "text/html").unwrap());
+                    request.respond(response).ok();
+                }
+            })
+        };
+
+        TestServer {
+            server,
+            handle: Some(handle),
+            requests,
+        }
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.server.server_addr().to_ip().unwrap())
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(handle) = self.handle.take() {
+            handle.join().ok();
+        }
+    }
+}
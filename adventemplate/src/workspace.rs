@@ -0,0 +1,204 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use toml_edit::{Array, DocumentMut, Item, Table};
+
+/// How day projects are laid out under the directory adventemplate is run in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ProjectLayout {
+    /// `dayN-title` right in the current directory
+    #[default]
+    Flat,
+    /// `{year}/dayN-title`, to keep several years of puzzles apart
+    Year,
+}
+
+impl ProjectLayout {
+    /// The path of the project for the given day, relative to the root directory (always `/` separated, as in a manifest)
+    pub fn project_path(&self, year: &str, project_name: &str) -> String {
+        match self {
+            ProjectLayout::Flat => project_name.to_string(),
+            ProjectLayout::Year => format!("{year}/{project_name}"),
+        }
+    }
+}
+
+/// Find the project for the day under the root directory, in either layout (a `dayN-...` or `dayN` folder with a `Cargo.toml`
+///  in it)
+pub fn find_project(root_path: &Path, year: &str, day_num: u8) -> Option<PathBuf> {
+    find_projects(root_path, year)
+        .into_iter()
+        .find(|(project_day_num, _)| *project_day_num == day_num)
+        .map(|(_, project_path)| project_path)
+}
+
+/// Every day's project under the root directory (in the year's folder first, then right in the root) in order of day
+pub fn find_projects(root_path: &Path, year: &str) -> Vec<(u8, PathBuf)> {
+    let mut projects = Vec::new();
+    for dir in [root_path.join(year), root_path.to_path_buf()] {
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        for path in entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()) {
+            let day_num = path
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .and_then(|file_name| file_name.strip_prefix("day"))
+                .map(|after_day| after_day.split_once('-').map_or(after_day, |(day_num, _)| day_num))
+                .and_then(|day_num| day_num.parse::<u8>().ok());
+            if let Some(day_num) = day_num {
+                if path.join("Cargo.toml").is_file() && !projects.iter().any(|(found_day_num, _)| *found_day_num == day_num) {
+                    projects.push((day_num, path));
+                }
+            }
+        }
+    }
+    projects.sort();
+    projects
+}
+
+/// What registering a member did to the root manifest
+#[derive(Debug, PartialEq, Eq)]
+pub enum MemberRegistration {
+    CreatedWorkspace,
+    AddedMember,
+    AlreadyMember,
+}
+
+/// Add the member to the `[workspace]` of the root `Cargo.toml`, creating the manifest or the workspace table in it if they
+///  aren't there yet. Anything else in the manifest is kept just as it was
+pub fn register_member(root_manifest_path: &Path, member: &str) -> io::Result<MemberRegistration> {
+    let existing_manifest = match fs::read_to_string(root_manifest_path) {
+        Ok(existing_manifest) => Some(existing_manifest),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+
+    let mut manifest = existing_manifest
+        .as_deref()
+        .unwrap_or_default()
+        .parse::<DocumentMut>()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Couldn't parse {}: {e}", root_manifest_path.display())))?;
+    let had_workspace = manifest.contains_key("workspace");
+
+    // Find (or set up) the workspace's list of members
+    let workspace = manifest
+        .entry("workspace")
+        .or_insert_with(|| {
+            let mut workspace = Table::new();
+            workspace.insert("resolver", toml_edit::value("2"));
+            Item::Table(workspace)
+        })
+        .as_table_mut()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "The root manifest's `workspace` isn't a table"))?;
+    let members = workspace
+        .entry("members")
+        .or_insert_with(|| toml_edit::value(Array::new()))
+        .as_array_mut()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "The root manifest's `workspace.members` isn't an array"))?;
+
+    if members.iter().any(|existing_member| existing_member.as_str() == Some(member)) {
+        return Ok(MemberRegistration::AlreadyMember);
+    }
+    members.push(member);
+
+    fs::write(root_manifest_path, manifest.to_string())?;
+    Ok(if had_workspace { MemberRegistration::AddedMember } else { MemberRegistration::CreatedWorkspace })
+}
+
+/// Whether the member is already listed in the root manifest's workspace
+pub fn is_member(root_manifest_path: &Path, member: &str) -> bool {
+    fs::read_to_string(root_manifest_path)
+        .ok()
+        .and_then(|manifest| manifest.parse::<DocumentMut>().ok())
+        .and_then(|manifest| {
+            manifest
+                .get("workspace")
+                .and_then(|workspace| workspace.get("members"))
+                .and_then(Item::as_array)
+                .map(|members| members.iter().any(|existing_member| existing_member.as_str() == Some(member)))
+        })
+        .unwrap_or(false)
+}
+
+// region: Tests
+
+#[test]
+fn creates_the_workspace_when_there_is_no_manifest() {
+    let root_dir = tempfile::tempdir().unwrap();
+    let root_manifest_path = root_dir.path().join("Cargo.toml");
+
+    assert_eq!(
+        register_member(&root_manifest_path, "2022/day1-calorie-counting").unwrap(),
+        MemberRegistration::CreatedWorkspace
+    );
+    assert_eq!(
+        register_member(&root_manifest_path, "2022/day2-rock-paper-scissors").unwrap(),
+        MemberRegistration::AddedMember
+    );
+    assert_eq!(
+        register_member(&root_manifest_path, "2022/day1-calorie-counting").unwrap(),
+        MemberRegistration::AlreadyMember
+    );
+
+    assert_eq!(
+        fs::read_to_string(&root_manifest_path).unwrap(),
+        "[workspace]\nresolver = \"2\"\nmembers = [\"2022/day1-calorie-counting\", \"2022/day2-rock-paper-scissors\"]\n"
+    );
+    assert!(is_member(&root_manifest_path, "2022/day2-rock-paper-scissors"));
+    assert!(!is_member(&root_manifest_path, "2022/day3-rucksack-reorganization"));
+}
+
+#[test]
+fn keeps_the_rest_of_an_existing_manifest() {
+    let root_dir = tempfile::tempdir().unwrap();
+    let root_manifest_path = root_dir.path().join("Cargo.toml");
+    fs::write(
+        &root_manifest_path,
+        "# My solutions\n[workspace]\nmembers = [\n    \"day1-calorie-counting\",\n]\n\n[profile.release]\ndebug = true\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        register_member(&root_manifest_path, "day2-rock-paper-scissors").unwrap(),
+        MemberRegistration::AddedMember
+    );
+
+    let manifest = fs::read_to_string(&root_manifest_path).unwrap();
+    assert!(manifest.starts_with("# My solutions\n[workspace]\n"));
+    assert!(manifest.contains("\"day1-calorie-counting\""));
+    assert!(manifest.contains("\"day2-rock-paper-scissors\""));
+    assert!(manifest.ends_with("[profile.release]\ndebug = true\n"));
+}
+
+#[test]
+fn finds_projects_in_either_layout() {
+    let root_dir = tempfile::tempdir().unwrap();
+    for project_path in ["day1-calorie-counting", "day12-hill-climbing-algorithm", "day99", "2021/day6-lanternfish"] {
+        fs::create_dir_all(root_dir.path().join(project_path)).unwrap();
+        fs::write(root_dir.path().join(project_path).join("Cargo.toml"), "").unwrap();
+    }
+
+    assert_eq!(find_project(root_dir.path(), "2022", 1), Some(root_dir.path().join("day1-calorie-counting")));
+    assert_eq!(find_project(root_dir.path(), "2021", 6), Some(root_dir.path().join("2021").join("day6-lanternfish")));
+    assert_eq!(find_project(root_dir.path(), "2022", 2), None);
+    assert_eq!(
+        find_projects(root_dir.path(), "2022"),
+        [
+            (1, root_dir.path().join("day1-calorie-counting")),
+            (12, root_dir.path().join("day12-hill-climbing-algorithm")),
+            (99, root_dir.path().join("day99")),
+        ]
+    );
+}
+
+#[test]
+fn project_paths_follow_the_layout() {
+    assert_eq!(ProjectLayout::Flat.project_path("2022", "day6-tuning-trouble"), "day6-tuning-trouble");
+    assert_eq!(ProjectLayout::Year.project_path("2021", "day6-lanternfish"), "2021/day6-lanternfish");
+}
+
+// endregion
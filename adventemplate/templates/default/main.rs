@@ -1,6 +1,6 @@
 fn main() {
     // Read in the {{ input_structure_name | replace(from="_", to=" ") }} from the question trimming any surrounding whitespace
-    let question_{{ input_structure_name }} = include_str!("../inputs/question").trim();
+    let question_{{ input_structure_name }} = include_str!("../inputs/{{ question_input }}").trim();
 
     // * Part One
     // <Whatever part one is doing>
@@ -26,13 +26,13 @@ fn parse_{{ input_structure_name }}({{ input_structure_name }}: &str) -> Vec<()>
 #[test]
 fn part_one_example_test() {
     // Read in the example {{ input_structure_name | replace(from="_", to=" ") }} trimming any surrounding whitespace
-    let example_{{ input_structure_name }} = include_str!("../inputs/example").trim();
+    let example_{{ input_structure_name }} = include_str!("../inputs/{{ example_input }}").trim();
 
     // <Part One goal> in the example input from the question
     let part_one_result = part_one(example_{{ input_structure_name }});
 
     // Check if the example yields the same result as the question describes
-    assert_eq!(part_one_result, 0);
+    assert_eq!(part_one_result, {{ part_one_expected }});
 }
 
 /// <Part One goal>
@@ -51,13 +51,13 @@ fn part_one({{ input_structure_name }}: &str) -> u32 {
 #[test]
 fn part_two_example_test() {
     // Read in the example {{ input_structure_name | replace(from="_", to=" ") }} trimming any surrounding whitespace
-    let example_{{ input_structure_name }} = include_str!("../inputs/example").trim();
+    let example_{{ input_structure_name }} = include_str!("../inputs/{{ example_input }}").trim();
 
     // <Part Two goal> in the example input from the question
     let part_two_result = part_two(example_{{ input_structure_name }});
 
     // Check if the example yields the same result as the question describes
-    assert_eq!(part_two_result, 0);
+    assert_eq!(part_two_result, {{ part_two_expected }});
 }
 
 /// <Part Two goal>
@@ -0,0 +1,104 @@
+fn main() {
+    // Read in the {{ input_structure_name | replace(from="_", to=" ") }} from the question trimming any surrounding whitespace
+    let question_{{ input_structure_name }} = include_str!("../inputs/{{ question_input }}").trim();
+
+    // * Part One
+    // <Whatever part one is doing>
+    let part_one_result = part_one(question_{{ input_structure_name }});
+    println!("Part One:\n  The result is: {part_one_result}");
+
+    // * Part Two
+    // <Whatever part two is doing>
+    let part_two_result = part_two(question_{{ input_structure_name }});
+    println!("Part Two:\n  The result is: {part_two_result}");
+}
+
+// region: Helpers
+
+/// A grid of cells indexed by (x, y), with y going down the rows of the input
+struct Grid<T> {
+    cells: Vec<Vec<T>>,
+}
+
+impl<T> Grid<T> {
+    fn width(&self) -> usize {
+        self.cells.first().map_or(0, |row| row.len())
+    }
+
+    fn height(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        self.cells.get(y).and_then(|row| row.get(x))
+    }
+
+    /// The positions up, down, left, and right of the given one that are still in the grid
+    fn neighbours(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        [(0, -1), (0, 1), (-1, 0), (1, 0)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+            .filter(|&(x, y)| x < self.width() && y < self.height())
+    }
+}
+
+fn parse_{{ input_structure_name }}({{ input_structure_name }}: &str) -> Grid<char> {
+    Grid {
+        cells: {{ input_structure_name }}
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect(),
+    }
+}
+
+// endregion
+
+// region: Part One
+
+#[test]
+fn part_one_example_test() {
+    // Read in the example {{ input_structure_name | replace(from="_", to=" ") }} trimming any surrounding whitespace
+    let example_{{ input_structure_name }} = include_str!("../inputs/{{ example_input }}").trim();
+
+    // <Part One goal> in the example input from the question
+    let part_one_result = part_one(example_{{ input_structure_name }});
+
+    // Check if the example yields the same result as the question describes
+    assert_eq!(part_one_result, {{ part_one_expected }});
+}
+
+/// <Part One goal>
+fn part_one({{ input_structure_name }}: &str) -> u32 {
+    // Parse the {{ input_structure_name | replace(from="_", to=" ") }} into a grid of cells
+    let grid = parse_{{ input_structure_name }}({{ input_structure_name }});
+
+    // The rest...
+    0
+}
+
+// endregion
+
+// region: Part Two
+
+#[test]
+fn part_two_example_test() {
+    // Read in the example {{ input_structure_name | replace(from="_", to=" ") }} trimming any surrounding whitespace
+    let example_{{ input_structure_name }} = include_str!("../inputs/{{ example_input }}").trim();
+
+    // <Part Two goal> in the example input from the question
+    let part_two_result = part_two(example_{{ input_structure_name }});
+
+    // Check if the example yields the same result as the question describes
+    assert_eq!(part_two_result, {{ part_two_expected }});
+}
+
+/// <Part Two goal>
+fn part_two({{ input_structure_name }}: &str) -> u32 {
+    // Parse the {{ input_structure_name | replace(from="_", to=" ") }} into a grid of cells
+    let grid = parse_{{ input_structure_name }}({{ input_structure_name }});
+
+    // The rest...
+    0
+}
+
+// endregion
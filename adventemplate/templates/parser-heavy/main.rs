@@ -0,0 +1,95 @@
+use std::str::FromStr;
+
+fn main() {
+    // Read in the {{ input_structure_name | replace(from="_", to=" ") }} from the question trimming any surrounding whitespace
+    let question_{{ input_structure_name }} = include_str!("../inputs/{{ question_input }}").trim();
+
+    // * Part One
+    // <Whatever part one is doing>
+    let part_one_result = part_one(question_{{ input_structure_name }});
+    println!("Part One:\n  The result is: {part_one_result}");
+
+    // * Part Two
+    // <Whatever part two is doing>
+    let part_two_result = part_two(question_{{ input_structure_name }});
+    println!("Part Two:\n  The result is: {part_two_result}");
+}
+
+// region: Helpers
+
+/// One line of the {{ input_structure_name | replace(from="_", to=" ") }}
+#[derive(Debug, PartialEq)]
+struct Entry {
+    // <What each line holds>
+}
+
+#[derive(Debug, PartialEq)]
+struct ParseEntryError(String);
+
+impl FromStr for Entry {
+    type Err = ParseEntryError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        // <Pick the line apart>
+        Err(ParseEntryError(format!("Don't know how to parse '{line}' yet")))
+    }
+}
+
+fn parse_{{ input_structure_name }}({{ input_structure_name }}: &str) -> Vec<Entry> {
+    {{ input_structure_name }}
+        .lines()
+        .map(|line| line.parse().expect("Every line of the input should parse"))
+        .collect()
+}
+
+// endregion
+
+// region: Part One
+
+#[test]
+fn part_one_example_test() {
+    // Read in the example {{ input_structure_name | replace(from="_", to=" ") }} trimming any surrounding whitespace
+    let example_{{ input_structure_name }} = include_str!("../inputs/{{ example_input }}").trim();
+
+    // <Part One goal> in the example input from the question
+    let part_one_result = part_one(example_{{ input_structure_name }});
+
+    // Check if the example yields the same result as the question describes
+    assert_eq!(part_one_result, {{ part_one_expected }});
+}
+
+/// <Part One goal>
+fn part_one({{ input_structure_name }}: &str) -> u32 {
+    // Parse each line of the {{ input_structure_name | replace(from="_", to=" ") }} into an entry
+    let entries = parse_{{ input_structure_name }}({{ input_structure_name }});
+
+    // The rest...
+    0
+}
+
+// endregion
+
+// region: Part Two
+
+#[test]
+fn part_two_example_test() {
+    // Read in the example {{ input_structure_name | replace(from="_", to=" ") }} trimming any surrounding whitespace
+    let example_{{ input_structure_name }} = include_str!("../inputs/{{ example_input }}").trim();
+
+    // <Part Two goal> in the example input from the question
+    let part_two_result = part_two(example_{{ input_structure_name }});
+
+    // Check if the example yields the same result as the question describes
+    assert_eq!(part_two_result, {{ part_two_expected }});
+}
+
+/// <Part Two goal>
+fn part_two({{ input_structure_name }}: &str) -> u32 {
+    // Parse each line of the {{ input_structure_name | replace(from="_", to=" ") }} into an entry
+    let entries = parse_{{ input_structure_name }}({{ input_structure_name }});
+
+    // The rest...
+    0
+}
+
+// endregion
@@ -0,0 +1,91 @@
+fn main() {
+    // Read in the {{ input_structure_name | replace(from="_", to=" ") }} from the question trimming any surrounding whitespace
+    let question_{{ input_structure_name }} = include_str!("../inputs/{{ question_input }}").trim();
+
+    // * Part One
+    // <Whatever part one is doing>
+    let part_one_result = part_one(question_{{ input_structure_name }});
+    println!("Part One:\n  The result is: {part_one_result}");
+
+    // * Part Two
+    // <Whatever part two is doing>
+    let part_two_result = part_two(question_{{ input_structure_name }});
+    println!("Part Two:\n  The result is: {part_two_result}");
+}
+
+// region: Helpers
+
+/// Everything that changes as the simulation runs
+#[derive(Clone, Debug)]
+struct State {
+    // <What the simulation keeps track of>
+}
+
+impl State {
+    /// Move the simulation along by one step, returning false once there's nothing left to do
+    fn step(&mut self) -> bool {
+        false
+    }
+}
+
+fn parse_{{ input_structure_name }}({{ input_structure_name }}: &str) -> State {
+    State {}
+}
+
+// endregion
+
+// region: Part One
+
+#[test]
+fn part_one_example_test() {
+    // Read in the example {{ input_structure_name | replace(from="_", to=" ") }} trimming any surrounding whitespace
+    let example_{{ input_structure_name }} = include_str!("../inputs/{{ example_input }}").trim();
+
+    // <Part One goal> in the example input from the question
+    let part_one_result = part_one(example_{{ input_structure_name }});
+
+    // Check if the example yields the same result as the question describes
+    assert_eq!(part_one_result, {{ part_one_expected }});
+}
+
+/// <Part One goal>
+fn part_one({{ input_structure_name }}: &str) -> u32 {
+    // Set up the starting state from the {{ input_structure_name | replace(from="_", to=" ") }}
+    let mut state = parse_{{ input_structure_name }}({{ input_structure_name }});
+
+    // Run the simulation until it settles
+    while state.step() {}
+
+    // The rest...
+    0
+}
+
+// endregion
+
+// region: Part Two
+
+#[test]
+fn part_two_example_test() {
+    // Read in the example {{ input_structure_name | replace(from="_", to=" ") }} trimming any surrounding whitespace
+    let example_{{ input_structure_name }} = include_str!("../inputs/{{ example_input }}").trim();
+
+    // <Part Two goal> in the example input from the question
+    let part_two_result = part_two(example_{{ input_structure_name }});
+
+    // Check if the example yields the same result as the question describes
+    assert_eq!(part_two_result, {{ part_two_expected }});
+}
+
+/// <Part Two goal>
+fn part_two({{ input_structure_name }}: &str) -> u32 {
+    // Set up the starting state from the {{ input_structure_name | replace(from="_", to=" ") }}
+    let mut state = parse_{{ input_structure_name }}({{ input_structure_name }});
+
+    // Run the simulation until it settles
+    while state.step() {}
+
+    // The rest...
+    0
+}
+
+// endregion
@@ -0,0 +1,473 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::Vec2;
+
+/// A 2D grid of cells addressed by world-space coordinates: the cell in the top left corner is at `offset` rather than at
+///  (0, 0), so a grid can cover just the part of the world that's interesting (like the bit of a cave the rocks are in).
+///  The cells are kept row by row in one vector so they're all in one contiguous place in memory
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    offset: Vec2<i32>,
+}
+
+/// A rectangle of world-space coordinates, with both corners included
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Vec2<i32>,
+    pub max: Vec2<i32>,
+}
+
+impl Bounds {
+    /// The bounds around just the one coordinate
+    pub fn point(coordinate: Vec2<i32>) -> Self {
+        Bounds { min: coordinate, max: coordinate }
+    }
+
+    pub fn contains(&self, coordinate: Vec2<i32>) -> bool {
+        (self.min.x..=self.max.x).contains(&coordinate.x) && (self.min.y..=self.max.y).contains(&coordinate.y)
+    }
+
+    /// Stretch the bounds (if needed) so that they take in the coordinate
+    pub fn include(&mut self, coordinate: Vec2<i32>) {
+        self.min = Vec2::new(self.min.x.min(coordinate.x), self.min.y.min(coordinate.y));
+        self.max = Vec2::new(self.max.x.max(coordinate.x), self.max.y.max(coordinate.y));
+    }
+
+    /// The number of columns, which is 0 if max is left of min
+    pub fn width(&self) -> usize {
+        usize::try_from(i64::from(self.max.x) - i64::from(self.min.x) + 1).unwrap_or_default()
+    }
+
+    /// The number of rows, which is 0 if max is above min
+    pub fn height(&self) -> usize {
+        usize::try_from(i64::from(self.max.y) - i64::from(self.min.y) + 1).unwrap_or_default()
+    }
+}
+
+/// One of the two directions in the grid
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+/// A coordinate that isn't in the grid, along with where the grid actually is so it's clear how far off it was
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GridIndexError {
+    /// The world-space coordinate that was asked for. For a row or column, this is where it would start
+    pub coordinate: Vec2<i32>,
+    /// The world-space coordinates the grid covers
+    pub bounds: Bounds,
+    /// Which way the coordinate is out of the grid (x first if it's out both ways)
+    pub axis: Axis,
+}
+
+impl fmt::Display for GridIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (axis, value, min, max) = match self.axis {
+            Axis::X => ("x", self.coordinate.x, self.bounds.min.x, self.bounds.max.x),
+            Axis::Y => ("y", self.coordinate.y, self.bounds.min.y, self.bounds.max.y),
+        };
+        write!(
+            f,
+            "({}, {}) is outside the grid: {axis} = {value} isn't in {min}..={max}",
+            self.coordinate.x, self.coordinate.y
+        )
+    }
+}
+
+impl Error for GridIndexError {}
+
+/// What can go wrong reading a grid from a block of characters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// A row that isn't as long as the first one
+    RaggedRow { row: usize, expected: usize, found: usize },
+    /// A character that doesn't stand for any kind of cell
+    BadCell { coordinate: Vec2<i32>, character: char },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::RaggedRow { row, expected, found } => {
+                write!(f, "Row {row} is {found} cells long, but the rows before it are {expected}")
+            }
+            ParseGridError::BadCell { coordinate, character } => {
+                write!(f, "'{character}' at ({}, {}) isn't a cell", coordinate.x, coordinate.y)
+            }
+        }
+    }
+}
+
+impl Error for ParseGridError {}
+
+/// Cells that can be drawn as a single character, which is what a grid's `Display` uses
+pub trait CellChar {
+    fn to_char(&self) -> char;
+}
+
+impl CellChar for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid of the given size with every cell set to `fill`, with its top left corner at `offset`
+    pub fn new(width: usize, height: usize, fill: T, offset: Vec2<i32>) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+            offset,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Read a grid from lines of characters (like most puzzle inputs), turning each character into a cell. The top left
+    ///  character ends up at (0, 0)
+    pub fn parse(text: &str, mut to_cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseGridError> {
+        let mut cells = Vec::with_capacity(text.len());
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in text.lines().enumerate() {
+            let row_start = cells.len();
+            for (x, character) in line.chars().enumerate() {
+                let coordinate = Vec2::new(x as i32, y as i32);
+                cells.push(to_cell(character).ok_or(ParseGridError::BadCell { coordinate, character })?);
+            }
+
+            // Every row has to be as wide as the first one
+            let row_width = cells.len() - row_start;
+            match width {
+                Some(expected) if expected != row_width => {
+                    return Err(ParseGridError::RaggedRow { row: y, expected, found: row_width })
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or_default(),
+            height,
+            offset: Vec2::default(),
+        })
+    }
+
+    /// The same grid moved so its top left corner is at `offset`
+    pub fn with_offset(self, offset: Vec2<i32>) -> Self {
+        Grid { offset, ..self }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The world-space coordinate of the top left cell
+    pub fn offset(&self) -> Vec2<i32> {
+        self.offset
+    }
+
+    /// The world-space coordinates that the grid covers
+    pub fn bounds(&self) -> Bounds {
+        Bounds {
+            min: self.offset,
+            max: self.offset + Vec2::new(self.width as i32 - 1, self.height as i32 - 1),
+        }
+    }
+
+    /// Whether the coordinate is one of the grid's cells
+    pub fn contains(&self, coordinate: Vec2<i32>) -> bool {
+        self.local_index(coordinate).is_ok()
+    }
+
+    /// The cell at the world-space coordinate
+    pub fn get(&self, coordinate: Vec2<i32>) -> Result<&T, GridIndexError> {
+        let local_index = self.local_index(coordinate)?;
+        Ok(&self.cells[local_index])
+    }
+
+    /// The cell at the world-space coordinate, to change
+    pub fn get_mut(&mut self, coordinate: Vec2<i32>) -> Result<&mut T, GridIndexError> {
+        let local_index = self.local_index(coordinate)?;
+        Ok(&mut self.cells[local_index])
+    }
+
+    /// The row at the world-space `y`, left to right
+    pub fn get_row(&self, y: i32) -> Result<&[T], GridIndexError> {
+        let local_y = self.local_axis(Vec2::new(self.offset.x, y), Axis::Y)?;
+        Ok(&self.cells[(local_y * self.width)..((local_y + 1) * self.width)])
+    }
+
+    /// The row at the world-space `y`, left to right, to change
+    pub fn get_row_mut(&mut self, y: i32) -> Result<&mut [T], GridIndexError> {
+        let local_y = self.local_axis(Vec2::new(self.offset.x, y), Axis::Y)?;
+        Ok(&mut self.cells[(local_y * self.width)..((local_y + 1) * self.width)])
+    }
+
+    /// The column at the world-space `x`, top to bottom. Columns aren't next to each other in memory, so this steps down
+    ///  the rows rather than being a slice
+    pub fn get_column(&self, x: i32) -> Result<impl DoubleEndedIterator<Item = &T> + ExactSizeIterator, GridIndexError> {
+        let local_x = self.local_axis(Vec2::new(x, self.offset.y), Axis::X)?;
+        Ok(self.cells[local_x..].iter().step_by(self.width).take(self.height))
+    }
+
+    /// Each row in turn, top to bottom
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // chunks panics on 0, and a grid with no width has no cells to split up anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every cell along with its world-space coordinate, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<i32>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.world_coordinate(i), cell))
+    }
+
+    /// Every cell, row by row
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The coordinate of the first cell (row by row) that matches
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Vec2<i32>> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.world_coordinate(i))
+    }
+
+    /// A grid of the same size and offset with each cell turned into something else
+    pub fn map<U>(&self, mut to_cell: impl FnMut(Vec2<i32>, &T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.iter().map(|(coordinate, cell)| to_cell(coordinate, cell)).collect(),
+            width: self.width,
+            height: self.height,
+            offset: self.offset,
+        }
+    }
+
+    /// The neighbours sharing an edge with the coordinate that are in the grid, along with their coordinates
+    pub fn neighbours4(&self, coordinate: Vec2<i32>) -> impl Iterator<Item = (Vec2<i32>, &T)> {
+        coordinate
+            .neighbours4()
+            .filter_map(|neighbour| Some((neighbour, self.get(neighbour).ok()?)))
+    }
+
+    /// The neighbours all around the coordinate (diagonals included) that are in the grid, along with their coordinates
+    pub fn neighbours8(&self, coordinate: Vec2<i32>) -> impl Iterator<Item = (Vec2<i32>, &T)> {
+        coordinate
+            .neighbours8()
+            .filter_map(|neighbour| Some((neighbour, self.get(neighbour).ok()?)))
+    }
+
+    /// Draw the grid a row to a line, turning each cell into a character with `to_char`
+    pub fn display_with<F: Fn(&T) -> char>(&self, to_char: F) -> DisplayWith<'_, T, F> {
+        DisplayWith { grid: self, to_char }
+    }
+
+    /// The local (from the top left) x or y of the world-space coordinate along the axis, if it's inside the grid that way
+    fn local_axis(&self, coordinate: Vec2<i32>, axis: Axis) -> Result<usize, GridIndexError> {
+        let (value, offset, size) = match axis {
+            Axis::X => (coordinate.x, self.offset.x, self.width),
+            Axis::Y => (coordinate.y, self.offset.y, self.height),
+        };
+        // (Checked, since a coordinate far enough from the offset doesn't fit in an i32 once it's local)
+        value
+            .checked_sub(offset)
+            .and_then(|local| usize::try_from(local).ok())
+            .filter(|local| *local < size)
+            .ok_or(GridIndexError { coordinate, bounds: self.bounds(), axis })
+    }
+
+    /// Where the world-space coordinate's cell is in the vector of cells
+    fn local_index(&self, coordinate: Vec2<i32>) -> Result<usize, GridIndexError> {
+        let local_x = self.local_axis(coordinate, Axis::X)?;
+        let local_y = self.local_axis(coordinate, Axis::Y)?;
+        Ok(local_y * self.width + local_x)
+    }
+
+    fn world_coordinate(&self, local_index: usize) -> Vec2<i32> {
+        self.offset + Vec2::new((local_index % self.width) as i32, (local_index / self.width) as i32)
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(text: &str) -> Result<Self, ParseGridError> {
+        Grid::parse(text, Some)
+    }
+}
+
+impl<T: CellChar> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display_with(T::to_char).fmt(f)
+    }
+}
+
+/// A grid drawn with a cell→char mapping, from `Grid::display_with`
+pub struct DisplayWith<'a, T, F> {
+    grid: &'a Grid<T>,
+    to_char: F,
+}
+
+impl<T, F: Fn(&T) -> char> fmt::Display for DisplayWith<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", (self.to_char)(cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// region: Tests
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+#[test]
+fn parsing_and_rendering_round_trips() {
+    let grid = EXAMPLE.parse::<Grid<char>>().unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (8, 5));
+    assert_eq!(grid.to_string(), EXAMPLE);
+    assert_eq!(grid.position(|cell| *cell == 'E'), Some(Vec2::new(5, 2)));
+    assert_eq!(
+        grid.display_with(|cell| if cell.is_uppercase() { '#' } else { '.' }).to_string().lines().next(),
+        Some("#.......")
+    );
+}
+
+#[test]
+fn bad_grids_say_where_they_went_wrong() {
+    assert_eq!(
+        "abc\nab\nabc".parse::<Grid<char>>(),
+        Err(ParseGridError::RaggedRow { row: 1, expected: 3, found: 2 })
+    );
+    assert_eq!(
+        Grid::parse("12\n3x", |character| character.to_digit(10)),
+        Err(ParseGridError::BadCell { coordinate: Vec2::new(1, 1), character: 'x' })
+    );
+}
+
+#[test]
+fn cells_are_found_by_world_space_coordinates() {
+    let mut grid = Grid::new(3, 2, '.', Vec2::new(498, 4));
+
+    *grid.get_mut(Vec2::new(500, 5)).unwrap() = '#';
+
+    assert_eq!(grid.get(Vec2::new(500, 5)), Ok(&'#'));
+    assert!(grid.get(Vec2::new(501, 5)).is_err());
+    assert!(grid.contains(Vec2::new(498, 4)));
+    assert!(!grid.contains(Vec2::new(497, 4)));
+    assert_eq!(grid.to_string(), "...\n..#");
+}
+
+#[test]
+fn bounds_cover_the_whole_grid() {
+    let grid = Grid::new(3, 2, '.', Vec2::new(-1, 5));
+
+    assert_eq!(grid.bounds(), Bounds { min: Vec2::new(-1, 5), max: Vec2::new(1, 6) });
+    assert_eq!((grid.bounds().width(), grid.bounds().height()), (3, 2));
+    assert_eq!(Grid::new(0, 0, '.', Vec2::default()).bounds().width(), 0);
+
+    let mut bounds = Bounds::point(Vec2::new(2, 2));
+    bounds.include(Vec2::new(-3, 4));
+    assert_eq!(bounds, Bounds { min: Vec2::new(-3, 2), max: Vec2::new(2, 4) });
+    assert!(bounds.contains(Vec2::new(0, 3)) && !bounds.contains(Vec2::new(0, 5)));
+}
+
+#[test]
+fn out_of_bounds_errors_say_which_way_and_by_how_much() {
+    let grid = Grid::new(10, 10, '.', Vec2::new(494, 0));
+    let bounds = Bounds { min: Vec2::new(494, 0), max: Vec2::new(503, 9) };
+
+    assert_eq!(
+        grid.get(Vec2::new(504, 3)),
+        Err(GridIndexError { coordinate: Vec2::new(504, 3), bounds, axis: Axis::X })
+    );
+    assert_eq!(grid.get(Vec2::new(500, -1)).unwrap_err().axis, Axis::Y);
+    assert_eq!(grid.get(Vec2::new(0, 100)).unwrap_err().axis, Axis::X);
+    assert_eq!(
+        grid.get(Vec2::new(500, 10)).unwrap_err().to_string(),
+        "(500, 10) is outside the grid: y = 10 isn't in 0..=9"
+    );
+    assert_eq!(grid.get_column(493).err().map(|error| error.coordinate), Some(Vec2::new(493, 0)));
+}
+
+#[test]
+fn coordinates_at_the_ends_of_i32_are_out_of_bounds() {
+    let grid = Grid::new(3, 3, '.', Vec2::new(1, 1));
+    assert_eq!(grid.get(Vec2::new(i32::MIN, 0)).unwrap_err().axis, Axis::X);
+    assert_eq!(grid.get(Vec2::new(2, i32::MAX)).unwrap_err().axis, Axis::Y);
+
+    // A grid right at the edge of i32, with a lookup from the other end
+    let grid = Grid::new(3, 3, '.', Vec2::new(i32::MAX - 2, 0));
+    assert_eq!(grid.get(Vec2::new(i32::MAX, 2)), Ok(&'.'));
+    assert_eq!(grid.get(Vec2::new(i32::MIN, 2)).unwrap_err().axis, Axis::X);
+
+    let bounds = Bounds { min: Vec2::new(i32::MIN, 0), max: Vec2::new(i32::MAX, 0) };
+    assert_eq!((bounds.width(), bounds.height()), (1 << 32, 1));
+}
+
+#[test]
+fn rows_and_columns_come_out_in_order() {
+    let mut grid = EXAMPLE.parse::<Grid<char>>().unwrap().with_offset(Vec2::new(-1, -1));
+
+    assert_eq!(grid.get_row(1).unwrap().iter().collect::<String>(), "accszExk");
+    assert_eq!(grid.get_column(-1).unwrap().collect::<String>(), "Saaaa");
+    assert_eq!(grid.get_column(6).unwrap().rev().collect::<String>(), "ijklm");
+    assert_eq!(grid.get_row(4).map_err(|error| error.axis), Err(Axis::Y));
+    assert!(grid.get_column(7).is_err());
+
+    grid.get_row_mut(-1).unwrap().fill('.');
+    assert_eq!(grid.rows().next().unwrap(), ['.'; 8]);
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = EXAMPLE.parse::<Grid<char>>().unwrap();
+
+    assert_eq!(
+        grid.neighbours4(Vec2::new(0, 0)).collect::<Vec<_>>(),
+        [(Vec2::new(0, 1), &'a'), (Vec2::new(1, 0), &'a')]
+    );
+    assert_eq!(grid.neighbours8(Vec2::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbours8(Vec2::new(3, 2)).count(), 8);
+}
+
+#[test]
+fn mapping_keeps_the_shape_and_offset() {
+    let grid = Grid::parse("123\n456", |character| character.to_digit(10)).unwrap().with_offset(Vec2::new(10, 20));
+
+    let sums = grid.map(|coordinate, digit| coordinate.x + coordinate.y + *digit as i32);
+
+    assert_eq!(sums.offset(), Vec2::new(10, 20));
+    assert_eq!(sums.cells().copied().collect::<Vec<_>>(), [31, 33, 35, 35, 37, 39]);
+}
+
+// endregion
@@ -0,0 +1,246 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    grid::{Bounds, CellChar},
+    Grid, Vec2,
+};
+
+/// How many cells a dense grid can take up before it gives up and switches to keeping cells in a `HashMap` (about 4
+///  million, so a few MB for small cells). Past that the data is almost certainly spread out, like day 15's sensors
+const DENSE_CELL_LIMIT: usize = 1 << 22;
+
+/// How far past a write a dense grid grows by default
+const DEFAULT_CHUNK_SIZE: usize = 16;
+
+/// A grid for simulations where the bounds aren't known up front. Writing anywhere makes room for it, and reading
+///  somewhere that's never been written gives back the fill cell. The cells live in a `Grid` that grows by chunks (moving
+///  its offset when it grows up or left), until that would get too big, at which point it falls back to a `HashMap` of
+///  just the cells that aren't the fill
+#[derive(Clone, Debug)]
+pub struct GrowingGrid<T> {
+    storage: Storage<T>,
+    fill: T,
+    /// The bounding box of everything written so far
+    bounds: Option<Bounds>,
+}
+
+#[derive(Clone, Debug)]
+enum Storage<T> {
+    Dense { grid: Grid<T>, chunk_size: usize },
+    Sparse(HashMap<Vec2<i32>, T>),
+}
+
+impl<T: Clone + PartialEq> GrowingGrid<T> {
+    /// An empty grid that keeps its cells densely, reading as `fill` everywhere to start with
+    pub fn new(fill: T) -> Self {
+        GrowingGrid {
+            storage: Storage::Dense {
+                grid: Grid::new(0, 0, fill.clone(), Vec2::default()),
+                chunk_size: DEFAULT_CHUNK_SIZE,
+            },
+            fill,
+            bounds: None,
+        }
+    }
+
+    /// An empty grid that keeps its cells in a `HashMap` from the start, for when it's known the data will be spread out
+    pub fn sparse(fill: T) -> Self {
+        GrowingGrid {
+            storage: Storage::Sparse(HashMap::new()),
+            fill,
+            bounds: None,
+        }
+    }
+
+    /// Grow by `chunk_size` cells past a write instead of the default. Bigger chunks mean fewer (but bigger) copies when
+    ///  growing. Doesn't do anything for a sparse grid
+    pub fn with_chunk_size(mut self, new_chunk_size: usize) -> Self {
+        if let Storage::Dense { chunk_size, .. } = &mut self.storage {
+            *chunk_size = new_chunk_size.max(1);
+        }
+        self
+    }
+
+    /// Whether the grid has fallen back to keeping its cells in a `HashMap`
+    pub fn is_sparse(&self) -> bool {
+        matches!(self.storage, Storage::Sparse(_))
+    }
+
+    /// The smallest box around every cell that's been written, or None if nothing has been yet
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// The cell at the coordinate, which is the fill cell if it's never been written
+    pub fn get(&self, coordinate: Vec2<i32>) -> &T {
+        match &self.storage {
+            Storage::Dense { grid, .. } => grid.get(coordinate).unwrap_or(&self.fill),
+            Storage::Sparse(cells) => cells.get(&coordinate).unwrap_or(&self.fill),
+        }
+    }
+
+    /// The cell at the coordinate to change, growing the grid to fit it if needed
+    pub fn get_mut(&mut self, coordinate: Vec2<i32>) -> &mut T {
+        self.make_room_for(coordinate);
+        match self.bounds.as_mut() {
+            Some(bounds) => bounds.include(coordinate),
+            None => self.bounds = Some(Bounds::point(coordinate)),
+        }
+
+        match &mut self.storage {
+            Storage::Dense { grid, .. } => grid.get_mut(coordinate).expect("The grid was just grown to fit"),
+            Storage::Sparse(cells) => cells.entry(coordinate).or_insert_with(|| self.fill.clone()),
+        }
+    }
+
+    pub fn set(&mut self, coordinate: Vec2<i32>, cell: T) {
+        *self.get_mut(coordinate) = cell;
+    }
+
+    /// A copy of the part of the grid inside the bounds as a regular `Grid`, e.g. for rendering. Empty if nothing has been
+    ///  written
+    pub fn to_grid(&self) -> Grid<T> {
+        let Some(bounds) = self.bounds else {
+            return Grid::new(0, 0, self.fill.clone(), Vec2::default());
+        };
+
+        Grid::new(bounds.width(), bounds.height(), self.fill.clone(), bounds.min)
+            .map(|coordinate, _| self.get(coordinate).clone())
+    }
+
+    /// Make sure that the storage covers the coordinate, growing the dense grid or switching over to sparse storage
+    fn make_room_for(&mut self, coordinate: Vec2<i32>) {
+        let Storage::Dense { grid, chunk_size } = &mut self.storage else {
+            return;
+        };
+        if grid.contains(coordinate) {
+            return;
+        }
+
+        // Grow a chunk past the coordinate on any side that it's outside of (stopping at the ends of i32)
+        let chunk = i32::try_from(*chunk_size).unwrap_or(i32::MAX);
+        let chunk_before = Vec2::new(coordinate.x.saturating_sub(chunk), coordinate.y.saturating_sub(chunk));
+        let chunk_after = Vec2::new(coordinate.x.saturating_add(chunk), coordinate.y.saturating_add(chunk));
+        let allocated = grid.bounds();
+        let grown = if grid.width() == 0 || grid.height() == 0 {
+            Bounds { min: chunk_before, max: chunk_after }
+        } else {
+            Bounds {
+                min: Vec2::new(
+                    if coordinate.x < allocated.min.x { chunk_before.x } else { allocated.min.x },
+                    if coordinate.y < allocated.min.y { chunk_before.y } else { allocated.min.y },
+                ),
+                max: Vec2::new(
+                    if coordinate.x > allocated.max.x { chunk_after.x } else { allocated.max.x },
+                    if coordinate.y > allocated.max.y { chunk_after.y } else { allocated.max.y },
+                ),
+            }
+        };
+
+        // If the grid would get too big, it's time to only keep track of the cells that aren't the fill
+        if grown.width().saturating_mul(grown.height()) > DENSE_CELL_LIMIT {
+            let cells = grid
+                .iter()
+                .filter(|(_, cell)| **cell != self.fill)
+                .map(|(coordinate, cell)| (coordinate, cell.clone()))
+                .collect();
+            self.storage = Storage::Sparse(cells);
+            return;
+        }
+
+        // Copy the old grid over into its place in the bigger one, a row at a time
+        let mut grown_grid = Grid::new(grown.width(), grown.height(), self.fill.clone(), grown.min);
+        // (In i64, since an empty grid's offset can be nowhere near where it's growing to)
+        let start_x = (i64::from(allocated.min.x) - i64::from(grown.min.x)) as usize;
+        for (y, row) in (allocated.min.y..=allocated.max.y).zip(grid.rows()) {
+            if let Ok(grown_row) = grown_grid.get_row_mut(y) {
+                grown_row[start_x..(start_x + row.len())].clone_from_slice(row);
+            }
+        }
+        *grid = grown_grid;
+    }
+}
+
+impl<T: Clone + PartialEq + CellChar> fmt::Display for GrowingGrid<T> {
+    /// Draw the part of the grid inside the bounds
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_grid().fmt(f)
+    }
+}
+
+// region: Tests
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[test]
+fn writes_anywhere_grow_the_grid() {
+    let mut grid = GrowingGrid::new('.').with_chunk_size(2);
+    assert_eq!(grid.bounds(), None);
+    assert_eq!(grid.to_string(), "");
+
+    grid.set(Vec2::new(500, 0), '+');
+    grid.set(Vec2::new(498, 3), '#');
+    grid.set(Vec2::new(503, -2), '#');
+
+    assert_eq!(grid.get(Vec2::new(500, 0)), &'+');
+    assert_eq!(grid.get(Vec2::new(-1000, 1000)), &'.');
+    assert_eq!(grid.bounds(), Some(Bounds { min: Vec2::new(498, -2), max: Vec2::new(503, 3) }));
+    assert_eq!(grid.to_string(), ".....#\n......\n..+...\n......\n......\n#.....");
+    assert!(!grid.is_sparse());
+}
+
+#[test]
+fn spread_out_data_falls_back_to_sparse() {
+    let mut grid = GrowingGrid::new(false);
+    grid.set(Vec2::new(0, 0), true);
+    grid.set(Vec2::new(4_000_000, 4_000_000), true);
+
+    assert!(grid.is_sparse());
+    assert!(*grid.get(Vec2::new(0, 0)));
+    assert!(*grid.get(Vec2::new(4_000_000, 4_000_000)));
+    assert!(!*grid.get(Vec2::new(1, 0)));
+    assert_eq!(grid.bounds().map(|bounds| bounds.width()), Some(4_000_001));
+}
+
+/// Mostly coordinates near the middle, but some right at the ends of i32 (within a chunk of them)
+#[cfg(test)]
+fn axis_value() -> impl Strategy<Value = i32> {
+    prop_oneof![8 => -50..50, 1 => (i32::MAX - 20)..=i32::MAX, 1 => i32::MIN..(i32::MIN + 20)]
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn dense_and_sparse_grids_agree_with_a_hashmap(
+        writes in prop::collection::vec(((axis_value(), axis_value()), 0..10_u8), 0..100),
+        chunk_size in 1..20_usize,
+    ) {
+        let mut dense = GrowingGrid::new(0).with_chunk_size(chunk_size);
+        let mut sparse = GrowingGrid::sparse(0);
+        let mut expected = HashMap::new();
+        for (coordinate, cell) in writes {
+            let coordinate = Vec2::from(coordinate);
+            dense.set(coordinate, cell);
+            sparse.set(coordinate, cell);
+            expected.insert(coordinate, cell);
+        }
+
+        let around_the_middle = (-51..51).flat_map(|x| (-51..51).map(move |y| Vec2::new(x, y)));
+        let corners = [i32::MIN, i32::MAX].into_iter().flat_map(|x| [i32::MIN, i32::MAX].map(|y| Vec2::new(x, y)));
+        for coordinate in around_the_middle.chain(corners).chain(expected.keys().copied()) {
+            let expected_cell = expected.get(&coordinate).unwrap_or(&0);
+            prop_assert_eq!(dense.get(coordinate), expected_cell);
+            prop_assert_eq!(sparse.get(coordinate), expected_cell);
+        }
+
+        prop_assert_eq!(dense.bounds(), sparse.bounds());
+        prop_assert!(expected.keys().all(|coordinate| dense.bounds().unwrap().contains(*coordinate)));
+        // (Only when they're small enough to draw - writes at both ends of i32 would take up the whole plane)
+        if dense.bounds().is_none_or(|bounds| bounds.width().saturating_mul(bounds.height()) <= 10_000) {
+            prop_assert_eq!(dense.to_grid(), sparse.to_grid());
+        }
+    }
+}
+
+// endregion
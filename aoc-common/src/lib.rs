@@ -0,0 +1,11 @@
+//! Bits and pieces that keep coming up across the days, so they don't get copy-pasted into each of them
+
+pub mod grid;
+pub mod growing_grid;
+pub mod solution;
+pub mod vec2;
+
+pub use grid::Grid;
+pub use growing_grid::GrowingGrid;
+pub use solution::Solution;
+pub use vec2::Vec2;
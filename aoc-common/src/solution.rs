@@ -0,0 +1,233 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+/// Which of a day's inputs to solve
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Input {
+    /// The small example from the puzzle description, whose answers are given in the description too
+    Example,
+    /// The real puzzle input
+    Question,
+}
+
+/// A day's puzzle solution, in a shape that lets one runner run, test and time any day
+pub trait Solution {
+    /// The day of the month the puzzle is for
+    const DAY: u8;
+
+    /// Anything the parts need beyond the input itself, which can be different for the example and the question (like
+    ///  day 15's row to check). `()` for most days
+    type Params;
+    type PartOne: Display + PartialEq + Debug;
+    type PartTwo: Display + PartialEq + Debug;
+
+    /// The text of the input, ready for the parts (usually trimmed)
+    fn input(input: Input) -> &'static str;
+
+    /// The parameters to go with the input
+    fn params(input: Input) -> Self::Params;
+
+    fn part_one(input: &str, params: &Self::Params) -> Self::PartOne;
+
+    fn part_two(input: &str, params: &Self::Params) -> Self::PartTwo;
+
+    /// The answers the puzzle description gives for the example
+    fn example_answers() -> (Self::PartOne, Self::PartTwo);
+
+    /// Solve part one for the input, with its parameters
+    fn solve_part_one(input: Input) -> Self::PartOne {
+        Self::part_one(Self::input(input), &Self::params(input))
+    }
+
+    /// Solve part two for the input, with its parameters
+    fn solve_part_two(input: Input) -> Self::PartTwo {
+        Self::part_two(Self::input(input), &Self::params(input))
+    }
+}
+
+/// How solving a day went, with the answers turned into strings so that any day's runs can go in the same list
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolutionRun {
+    pub day: u8,
+    pub part_one: String,
+    pub part_two: String,
+    pub part_one_time: Duration,
+    pub part_two_time: Duration,
+}
+
+/// A part whose answer for the example isn't the one from the puzzle description
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleMismatch {
+    pub day: u8,
+    pub part: u8,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for ExampleMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {} part {} gave {:?} for the example, but it should be {:?}",
+            self.day, self.part, self.actual, self.expected
+        )
+    }
+}
+
+/// A `Solution` with its types hidden away, so that every day can be kept in the one `Registry`
+pub trait RegisteredSolution {
+    fn day(&self) -> u8;
+
+    /// Solve both parts for the input, timing each of them
+    fn run(&self, input: Input) -> SolutionRun;
+
+    /// Solve both parts for the example and compare them with the answers from the puzzle description, returning the
+    ///  ones that don't match
+    fn test(&self) -> Vec<ExampleMismatch>;
+}
+
+struct Registered<S>(PhantomData<S>);
+
+impl<S: Solution> RegisteredSolution for Registered<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: Input) -> SolutionRun {
+        let (part_one, part_one_time) = time(|| S::solve_part_one(input));
+        let (part_two, part_two_time) = time(|| S::solve_part_two(input));
+        SolutionRun {
+            day: S::DAY,
+            part_one: part_one.to_string(),
+            part_two: part_two.to_string(),
+            part_one_time,
+            part_two_time,
+        }
+    }
+
+    fn test(&self) -> Vec<ExampleMismatch> {
+        let (expected_part_one, expected_part_two) = S::example_answers();
+        let part_one = S::solve_part_one(Input::Example);
+        let part_two = S::solve_part_two(Input::Example);
+
+        let mut mismatches = Vec::new();
+        if part_one != expected_part_one {
+            mismatches.push(ExampleMismatch {
+                day: S::DAY,
+                part: 1,
+                expected: expected_part_one.to_string(),
+                actual: part_one.to_string(),
+            });
+        }
+        if part_two != expected_part_two {
+            mismatches.push(ExampleMismatch {
+                day: S::DAY,
+                part: 2,
+                expected: expected_part_two.to_string(),
+                actual: part_two.to_string(),
+            });
+        }
+        mismatches
+    }
+}
+
+fn time<T>(solve: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let answer = solve();
+    (answer, start.elapsed())
+}
+
+/// Every day's solution, looked up by day number
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<u8, Box<dyn RegisteredSolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Add the day's solution, replacing any that was registered for the same day before
+    pub fn register<S: Solution + 'static>(&mut self) -> &mut Self {
+        self.solutions.insert(S::DAY, Box::new(Registered::<S>(PhantomData)));
+        self
+    }
+
+    pub fn get(&self, day: u8) -> Option<&dyn RegisteredSolution> {
+        self.solutions.get(&day).map(|solution| solution.as_ref())
+    }
+
+    /// The days that have a solution, in order
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.solutions.keys().copied()
+    }
+}
+
+// region: Tests
+
+#[cfg(test)]
+struct Doubling;
+
+#[cfg(test)]
+impl Solution for Doubling {
+    const DAY: u8 = 26;
+    type Params = u32;
+    type PartOne = u32;
+    type PartTwo = String;
+
+    fn input(input: Input) -> &'static str {
+        match input {
+            Input::Example => "2",
+            Input::Question => "21",
+        }
+    }
+
+    fn params(input: Input) -> u32 {
+        match input {
+            Input::Example => 2,
+            Input::Question => 3,
+        }
+    }
+
+    fn part_one(input: &str, times: &u32) -> u32 {
+        input.parse::<u32>().unwrap() * times
+    }
+
+    fn part_two(input: &str, _: &u32) -> String {
+        input.repeat(2)
+    }
+
+    fn example_answers() -> (u32, String) {
+        (4, "23".to_string())
+    }
+}
+
+#[test]
+fn registered_days_run_with_their_params() {
+    let mut registry = Registry::new();
+    registry.register::<Doubling>();
+
+    assert_eq!(registry.days().collect::<Vec<_>>(), [26]);
+    assert!(registry.get(1).is_none());
+
+    let run = registry.get(26).unwrap().run(Input::Question);
+    assert_eq!((run.day, run.part_one.as_str(), run.part_two.as_str()), (26, "63", "2121"));
+}
+
+#[test]
+fn testing_reports_the_parts_that_dont_match_the_example() {
+    let mut registry = Registry::new();
+    registry.register::<Doubling>();
+
+    assert_eq!(
+        registry.get(26).unwrap().test(),
+        [ExampleMismatch { day: 26, part: 2, expected: "23".to_string(), actual: "22".to_string() }]
+    );
+}
+
+// endregion
@@ -0,0 +1,291 @@
+use std::{fmt::Debug, hash::Hash, ops};
+
+/// A 2D vector that does for coordinates, positions, directions and offsets alike. Works for signed numbers (positions that
+///  can go negative, like a rope's knots) as well as unsigned ones (indices into a grid)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// The numbers a `Vec2` can be made of. Implemented for all the built-in integers
+pub trait Scalar: Copy + Debug + Ord + Hash + ops::Add<Output = Self> + ops::Sub<Output = Self> + ops::Mul<Output = Self> {
+    /// What distances come out as, so that they can't be negative
+    type Distance: Copy + Debug + Ord + ops::Add<Output = Self::Distance>;
+
+    const ZERO: Self;
+
+    /// How far apart the two numbers are
+    fn abs_diff(self, other: Self) -> Self::Distance;
+
+    /// -1, 0 or 1 depending on the sign (unsigned numbers are never -1)
+    fn signum(self) -> Self;
+
+    /// Move by -1, 0 or 1, or None if that'd go past the end of the type (like below 0 for a usize)
+    fn checked_step(self, step: i8) -> Option<Self>;
+}
+
+macro_rules! impl_scalar {
+    (signed: $($signed:ty => $distance:ty),*) => {
+        $(
+            impl Scalar for $signed {
+                type Distance = $distance;
+
+                const ZERO: Self = 0;
+
+                fn abs_diff(self, other: Self) -> $distance {
+                    self.abs_diff(other)
+                }
+
+                fn signum(self) -> Self {
+                    self.signum()
+                }
+
+                fn checked_step(self, step: i8) -> Option<Self> {
+                    self.checked_add(<$signed>::from(step))
+                }
+            }
+        )*
+    };
+    (unsigned: $($unsigned:ty => $step:ty),*) => {
+        $(
+            impl Scalar for $unsigned {
+                type Distance = $unsigned;
+
+                const ZERO: Self = 0;
+
+                fn abs_diff(self, other: Self) -> $unsigned {
+                    self.abs_diff(other)
+                }
+
+                fn signum(self) -> Self {
+                    (self > 0).into()
+                }
+
+                fn checked_step(self, step: i8) -> Option<Self> {
+                    self.checked_add_signed(<$step>::from(step))
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(signed: i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+impl_scalar!(unsigned: u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128, usize => isize);
+
+/// The steps to the four neighbours that share an edge: up, right, down, left (with y going up)
+const ORTHOGONAL_STEPS: [(i8, i8); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+/// The steps to the four neighbours that only share a corner, going round the same way as the orthogonal ones
+const DIAGONAL_STEPS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, -1), (-1, 1)];
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T: Scalar> Vec2<T> {
+    /// The number of steps between the two if you can only move along the axes, aka taxicab distance
+    pub fn manhattan_distance(self, other: Self) -> T::Distance {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of steps between the two if diagonal moves are allowed too, aka chessboard distance
+    pub fn chebyshev_distance(self, other: Self) -> T::Distance {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The sign of each component, which turns an offset into a single step in its direction (diagonal ones included)
+    pub fn signum(self) -> Self {
+        Vec2 { x: self.x.signum(), y: self.y.signum() }
+    }
+
+    /// The up to four neighbours that share an edge with this one. Neighbours past the end of the type (like a negative
+    ///  index) are left out
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        ORTHOGONAL_STEPS.into_iter().filter_map(move |step| self.checked_step(step))
+    }
+
+    /// The up to eight neighbours around this one, diagonals included. Neighbours past the end of the type (like a negative
+    ///  index) are left out
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        ORTHOGONAL_STEPS
+            .into_iter()
+            .chain(DIAGONAL_STEPS)
+            .filter_map(move |step| self.checked_step(step))
+    }
+
+    fn checked_step(self, (step_x, step_y): (i8, i8)) -> Option<Self> {
+        Some(Vec2 { x: self.x.checked_step(step_x)?, y: self.y.checked_step(step_y)? })
+    }
+}
+
+impl<T: ops::Neg<Output = T>> Vec2<T> {
+    /// A quarter turn anticlockwise around the origin when y goes up (which is clockwise on screen, where y goes down)
+    pub fn rotate_left(self) -> Self {
+        Vec2 { x: -self.y, y: self.x }
+    }
+
+    /// A quarter turn clockwise around the origin when y goes up (which is anticlockwise on screen, where y goes down)
+    pub fn rotate_right(self) -> Self {
+        Vec2 { x: self.y, y: -self.x }
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(vec2: Vec2<T>) -> Self {
+        (vec2.x, vec2.y)
+    }
+}
+
+// region: Operators
+
+impl<T: ops::Add<Output = T>> ops::Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Vec2 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: ops::Sub<Output = T>> ops::Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Vec2 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+/// Scaling by a number, e.g. a direction times the number of steps
+impl<T: ops::Mul<Output = T> + Copy> ops::Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Vec2 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: ops::Neg<Output = T>> ops::Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Vec2 { x: -self.x, y: -self.y }
+    }
+}
+
+impl<T: ops::AddAssign> ops::AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: ops::SubAssign> ops::SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+// endregion
+
+// region: Tests
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+fn any_vec2() -> impl Strategy<Value = Vec2<i32>> {
+    // Small enough that nothing overflows when they're added, scaled or measured
+    (-10_000..10_000, -10_000..10_000).prop_map(Vec2::from)
+}
+
+#[test]
+fn neighbours_past_the_edge_of_the_type_are_left_out() {
+    let corner = Vec2::<usize>::new(0, 0);
+
+    assert_eq!(corner.neighbours4().collect::<Vec<_>>(), [Vec2::new(0, 1), Vec2::new(1, 0)]);
+    assert_eq!(corner.neighbours8().count(), 3);
+    assert_eq!(Vec2::new(-1_i32, 4).neighbours8().count(), 8);
+}
+
+#[test]
+fn signum_points_the_way() {
+    assert_eq!(Vec2::new(-7, 3).signum(), Vec2::new(-1, 1));
+    assert_eq!(Vec2::new(0, -2).signum(), Vec2::new(0, -1));
+    assert_eq!(Vec2::<u8>::new(0, 9).signum(), Vec2::new(0, 1));
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn adding_then_subtracting_gets_back_where_it_started(a in any_vec2(), b in any_vec2()) {
+        prop_assert_eq!(a + b - b, a);
+        let mut c = a;
+        c += b;
+        c -= b;
+        prop_assert_eq!(c, a);
+        prop_assert_eq!(a + -a, Vec2::default());
+    }
+
+    #[test]
+    fn scaling_is_the_same_as_adding_over_and_over(a in any_vec2(), times in 0..10) {
+        prop_assert_eq!(a * times, (0..times).fold(Vec2::default(), |sum, _| sum + a));
+    }
+
+    #[test]
+    fn distances_are_symmetric_and_ordered(a in any_vec2(), b in any_vec2()) {
+        prop_assert_eq!(a.manhattan_distance(b), b.manhattan_distance(a));
+        prop_assert_eq!(a.chebyshev_distance(b), b.chebyshev_distance(a));
+        prop_assert!(a.chebyshev_distance(b) <= a.manhattan_distance(b));
+        prop_assert!(a.manhattan_distance(b) <= 2 * a.chebyshev_distance(b));
+        prop_assert_eq!(a.manhattan_distance(a), 0);
+    }
+
+    #[test]
+    fn manhattan_distance_obeys_the_triangle_inequality(a in any_vec2(), b in any_vec2(), c in any_vec2()) {
+        prop_assert!(a.manhattan_distance(c) <= a.manhattan_distance(b) + b.manhattan_distance(c));
+    }
+
+    #[test]
+    fn neighbours_are_one_step_away(a in any_vec2()) {
+        prop_assert!(a.neighbours4().all(|neighbour| a.manhattan_distance(neighbour) == 1));
+        prop_assert!(a.neighbours8().all(|neighbour| a.chebyshev_distance(neighbour) == 1));
+        prop_assert_eq!(a.neighbours4().count(), 4);
+        prop_assert_eq!(a.neighbours8().collect::<std::collections::HashSet<_>>().len(), 8);
+    }
+
+    #[test]
+    fn a_signum_step_gets_closer(a in any_vec2(), b in any_vec2()) {
+        prop_assume!(a != b);
+        let step = (b - a).signum();
+        prop_assert_eq!(a.chebyshev_distance(a + step), 1);
+        prop_assert_eq!((a + step).chebyshev_distance(b), a.chebyshev_distance(b) - 1);
+    }
+
+    #[test]
+    fn four_quarter_turns_go_all_the_way_round(a in any_vec2()) {
+        prop_assert_eq!(a.rotate_left().rotate_left().rotate_left().rotate_left(), a);
+        prop_assert_eq!(a.rotate_left().rotate_right(), a);
+        prop_assert_eq!(a.rotate_left().rotate_left(), -a);
+        prop_assert_eq!(a.rotate_left().manhattan_distance(Vec2::default()), a.manhattan_distance(Vec2::default()));
+    }
+}
+
+// endregion
@@ -0,0 +1,181 @@
+use std::process::ExitCode;
+
+use aoc_common::solution::{Input, Registry, SolutionRun};
+use clap::{Parser, Subcommand};
+
+// Define program input with clap
+#[derive(Parser)]
+struct RunnerInput {
+    #[command(subcommand)]
+    command: RunnerCommand,
+}
+
+#[derive(Subcommand)]
+enum RunnerCommand {
+    /// Solve the days, printing their answers and how long each part took
+    Run {
+        #[arg(help = "The days to run. Every registered day if left out.")]
+        days: Vec<u8>,
+
+        #[arg(long, help = "Solve the example from the puzzle description instead of the question input.")]
+        example: bool,
+    },
+    /// Solve the days' examples and check the answers against the ones from the puzzle descriptions
+    Test {
+        #[arg(help = "The days to test. Every registered day if left out.")]
+        days: Vec<u8>,
+    },
+}
+
+fn main() -> ExitCode {
+    let runner_input = RunnerInput::parse();
+    let registry = registry();
+
+    match runner_input.command {
+        RunnerCommand::Run { days, example } => {
+            let input = if example { Input::Example } else { Input::Question };
+            run_days(&registry, &days, input)
+        }
+        RunnerCommand::Test { days } => test_days(&registry, &days),
+    }
+}
+
+/// Every day with a solution. New days need adding here (and to Cargo.toml) to be run by number
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<day1_calorie_counting::Day1>()
+        .register::<day2_rock_paper_scissors::Day2>()
+        .register::<day3_rucksack_reorganization::Day3>()
+        .register::<day4_camp_cleanup::Day4>()
+        .register::<day5_supply_stacks::Day5>()
+        .register::<day6_tuning_trouble::Day6>()
+        .register::<day7_no_space_left_on_device::Day7>()
+        .register::<day8_treetop_tree_house::Day8>()
+        .register::<day9_rope_bridge::Day9>()
+        .register::<day10_cathode_ray_tube::Day10>()
+        .register::<day11_monkey_in_the_middle::Day11>()
+        .register::<day12_hill_climbing_algorithm::Day12>()
+        .register::<day13_distress_signal::Day13>()
+        .register::<day14_regolith_reservoir::Day14>()
+        .register::<day15_beacon_exclusion_zone::Day15>();
+    registry
+}
+
+/// The days that were asked for, or every registered day if none were. Errors with the first day that doesn't have a
+///  solution
+fn select_days(registry: &Registry, days: &[u8]) -> Result<Vec<u8>, u8> {
+    if days.is_empty() {
+        return Ok(registry.days().collect());
+    }
+    match days.iter().find(|day| registry.get(**day).is_none()) {
+        Some(missing_day) => Err(*missing_day),
+        None => Ok(days.to_vec()),
+    }
+}
+
+/// Run each of the days on the input, printing the answers and times as it goes
+fn run_days(registry: &Registry, days: &[u8], input: Input) -> ExitCode {
+    let days = match select_days(registry, days) {
+        Ok(days) => days,
+        Err(missing_day) => {
+            eprintln!("There's no solution registered for day {missing_day}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for day in days {
+        let solution_run = registry.get(day).expect("Days were checked to be registered").run(input);
+        println!("{}", format_run(&solution_run));
+    }
+    ExitCode::SUCCESS
+}
+
+/// Test each of the days against their examples, failing if any of them don't match
+fn test_days(registry: &Registry, days: &[u8]) -> ExitCode {
+    let days = match select_days(registry, days) {
+        Ok(days) => days,
+        Err(missing_day) => {
+            eprintln!("There's no solution registered for day {missing_day}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut all_passed = true;
+    for day in days {
+        let mismatches = registry.get(day).expect("Days were checked to be registered").test();
+        if mismatches.is_empty() {
+            println!("Day {day}: ok");
+        } else {
+            all_passed = false;
+            for mismatch in mismatches {
+                println!("{mismatch}");
+            }
+        }
+    }
+
+    if all_passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Lay out a day's answers with how long they took. Answers that take up several lines (like day 10's screen) start on
+///  the line after their part
+fn format_run(solution_run: &SolutionRun) -> String {
+    let format_part = |label: &str, answer: &str, time| {
+        if answer.contains('\n') {
+            format!("  {label} ({time:.2?}):\n{answer}")
+        } else {
+            format!("  {label} ({time:.2?}): {answer}")
+        }
+    };
+
+    [
+        format!("Day {}", solution_run.day),
+        format_part("Part One", &solution_run.part_one, solution_run.part_one_time),
+        format_part("Part Two", &solution_run.part_two, solution_run.part_two_time),
+    ]
+    .join("\n")
+}
+
+// region: Tests
+
+#[test]
+fn every_registered_day_solves_its_example() {
+    let registry = registry();
+
+    assert_eq!(registry.days().collect::<Vec<_>>(), (1..=15).collect::<Vec<_>>());
+    for day in registry.days() {
+        let mismatches = registry.get(day).unwrap().test();
+        assert!(mismatches.is_empty(), "{mismatches:?}");
+    }
+}
+
+#[test]
+fn unknown_days_are_picked_out() {
+    let registry = registry();
+
+    assert_eq!(select_days(&registry, &[]).map(|days| days.len()), Ok(15));
+    assert_eq!(select_days(&registry, &[15, 1]), Ok(vec![15, 1]));
+    assert_eq!(select_days(&registry, &[3, 26]), Err(26));
+}
+
+#[test]
+fn multi_line_answers_start_on_their_own_line() {
+    let solution_run = SolutionRun {
+        day: 10,
+        part_one: "13140".to_string(),
+        part_two: "##..\n..##".to_string(),
+        part_one_time: std::time::Duration::from_millis(1),
+        part_two_time: std::time::Duration::from_millis(2),
+    };
+
+    assert_eq!(
+        format_run(&solution_run),
+        "Day 10\n  Part One (1.00ms): 13140\n  Part Two (2.00ms):\n##..\n..##"
+    );
+}
+
+// endregion
@@ -0,0 +1,298 @@
+use std::{iter, thread, time::Duration};
+
+use aoc_common::{solution::{Input, Solution}, GrowingGrid, Vec2};
+
+// region: Solution
+
+/// Day 14, for the runner
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Params = ();
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn input(input: Input) -> &'static str {
+        match input {
+            Input::Example => include_str!("../inputs/example").trim(),
+            Input::Question => include_str!("../inputs/question").trim(),
+        }
+    }
+
+    fn params(_: Input) {}
+
+    fn part_one(input: &str, _: &()) -> u32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str, _: &()) -> u32 {
+        part_two(input)
+    }
+
+    fn example_answers() -> (u32, u32) {
+        (24, 93)
+    }
+}
+
+// endregion
+
+// region: Cave Types
+
+/// A position in the cave (with y going down, deeper into it)
+type Coord = Vec2<i32>;
+
+/// The cave, which grows to fit whatever gets put in it: '.' for air, '#' for rock, 'o' for sand and '+' for the sand source
+type CaveMap = GrowingGrid<char>;
+
+// endregion
+
+// region: Helpers
+
+/// Parse the cave structure into a vector of rock paths (represented by a vector of the coordinates of each vertex in the path)
+fn parse_cave_structure(cave_structure: &str) -> Vec<Vec<Coord>> {
+    cave_structure
+        .split('\n')
+        .map(|path| {
+            path.split(" -> ")
+                .map(|coord| {
+                    // Read the coordinates str
+                    let (x, y) = coord
+                        .split_once(',')
+                        .expect("Invalid cave coordinate - bad input");
+
+                    // Parse x and y into coordinate numbers
+                    Coord {
+                        x: x.parse::<i32>().expect("Missing coordinate number x"),
+                        y: y.parse::<i32>().expect("Missing coordinate number y"),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Generate the cave map from the `rock_structures` and return it
+fn generate_cave_map(rock_structures: Vec<Vec<Coord>>, sand_source: Coord) -> CaveMap {
+    // Create the cave map, initially filling it with air. It grows to fit the rocks as they're added
+    let mut cave_map = CaveMap::new('.');
+
+    // Add the sand source to the cave map
+    cave_map.set(sand_source, '+');
+
+    // Add each of the rock path structures to the cave map
+    for rock_path in rock_structures {
+        let rock_path_final_coord = rock_path
+            .iter()
+            .reduce(|prev_coord, current_coord| {
+                // Note: for now, we are going to assume the input is good and that we won't be creating any infinite iters haha
+
+                // Get the direction of the rock line starting at the prev_coord and ending at current_coord
+                let rock_line_direction = (*current_coord - *prev_coord).signum();
+
+                // Create an iter that can traverse the rock line from prev to current
+                let mut position = *prev_coord;
+                let rock_line_iter = iter::from_fn(|| {
+                    // Check if we are done with the line segment - if the last position was the end position of the line, be done
+                    if position == *current_coord { return None; }
+
+                    // Record the position to return with the iter (we want it to iterate over the first coord so we will record this before incrementing)
+                    let iter_position = position;
+
+                    // Increment position by direction for the next iter to use
+                    position += rock_line_direction;
+
+                    // Return the iter posiion
+                    Some(iter_position)
+                });
+
+                // Fill each of the positions in the line between the two points with rock, aka '#'
+                for rock_coord in rock_line_iter {
+                    cave_map.set(rock_coord, '#');
+                }
+
+                // Pass the current_coord as the prev_coord for the next elem
+                current_coord
+            })
+            .expect(
+                "Failed to traverse the whole (or possibly just the last part of) the rock path",
+            );
+
+        // Fill in the last element of the reduced rock path with rock, aka '#'
+        cave_map.set(*rock_path_final_coord, '#');
+    }
+
+    // Return the cave map
+    cave_map
+}
+
+/// Simulates a a sand partical falling, returning the coordinate of the final position it came to a rest or None if it fell into the abyss.
+///  If there's a `floor_y`, there's an endless floor of rock there for the sand to land on, so it can't fall into the abyss
+fn simulate_sand_particle_falling(cave_map: &CaveMap, sand_source: Coord, floor_y: Option<i32>) -> Option<Coord> {
+    // Spawn a unit of sand at the sand source
+    let mut particle_position = sand_source;
+
+    // Anything that falls past the lowest rock is in the abyss (sand only ever comes to rest on top of something, so the
+    //  bottom of the cave map is always the lowest rock)
+    let abyss_y = cave_map.bounds().expect("The cave map is empty").max.y;
+
+    // Define the direction checks that the sand physics follow
+    let direction_checks = [(0, 1), (-1, 1), (1, 1)]
+        .into_iter()
+        .map(|(x, y)| Coord { x, y })
+        .collect::<Vec<_>>();
+
+    // Create an iterator that returns positions of a unit of sand until it comes to rest
+    let sand_movement_iter = iter::from_fn(|| {
+        // Get the next position this sand particle should go, bubbling up None (with ?) if there wasn't one
+        let direction = direction_checks.iter().find(|direction| {
+            // Find the first direction in which the poisition is open - not rock, sand, or the floor
+            let next_position = particle_position + **direction;
+            floor_y != Some(next_position.y) && !matches!(cave_map.get(next_position), '#' | 'o')
+        })?;
+
+        // Update the particle position in the selected direction
+        particle_position += *direction;
+
+        // Return the new position
+        Some(particle_position)
+    });
+
+    // Iterate through our sand movement iterator to simulate the sand falling. The iterator will stop once the sand has come to rest
+    for next_position in sand_movement_iter {
+        // If sand falls past all the rock, the cave structure is full of sand (just like professor Zei's life's ambition), so we can return the number of units of sand that have come to a rest
+        // ? Note: We could animate the next poisition here
+        if floor_y.is_none() && next_position.y > abyss_y {
+            return None;
+        }
+    }
+
+    // Return the final position
+    Some(particle_position)
+}
+
+// Only used by the animation, which is commented out in the parts
+#[allow(dead_code)]
+fn animate_cave_map(cave_map: &CaveMap) {
+    // Move the cursor to the start of the screen
+    print!("\x1B[1;1H");
+
+    // Print the cave map
+    println!("{cave_map}");
+
+    // Sleep for a certain amount of time to create a visible framerate in the animation
+    thread::sleep(Duration::from_secs_f32(0.02));
+}
+
+// endregion
+
+// region: Part One
+
+#[test]
+fn part_one_example_test() {
+    // Read in the example cave structure trimming any surrounding whitespace
+    let example_cave_structure = include_str!("../inputs/example").trim();
+
+    // Simulate sand falling into the cave and count the number of particles of sand that pile up in it in the example input from the question
+    let total_sand_count = part_one(example_cave_structure);
+
+    // Check if the example yields the same result as the question describes
+    assert_eq!(total_sand_count, 24);
+}
+
+/// Generate a cave map and simulate sand falling into it, counting the number of units of sands that it takes to fill it up
+fn part_one(cave_structure: &str) -> u32 {
+    // Parse the cave_structure input into a vector of rock paths
+    let rock_structures = parse_cave_structure(cave_structure);
+
+    // Define the coordinate of the sand source of where sand is spilling in from
+    let sand_source = Coord { x: 500, y: 0 };
+
+    // Generate the cave map given the rock structure and the location of the sand source
+    let mut cave_map = generate_cave_map(rock_structures, sand_source);
+
+    // println!("The cave_map looks like:\n{cave_map}");
+
+    // * Animation
+    // Clear the screen
+    // print!("\x1B[2J\x1B[1;1H");
+
+    // Starting simulating sand falling
+    let mut resting_sand_count = 0;
+    // Simulate each particle falling and retrieve its final position, stopping once sand has begun to fall into the abyss
+    while let Some(sand_position) = simulate_sand_particle_falling(&cave_map, sand_source, None) {
+        // If sand comes to a stop, increment the count of units of sand that have come to a rest and place the sand in its final location
+        resting_sand_count += 1;
+        cave_map.set(sand_position, 'o');
+
+        // * Animate: print the cave_map
+        // animate_cave_map(&cave_map);
+    }
+
+    // Return the count of the grains of sand that have come to rest
+    resting_sand_count
+}
+
+// endregion
+
+// region: Part Two
+
+#[test]
+fn part_two_example_test() {
+    // Read in the example cave structure trimming any surrounding whitespace
+    let example_cave_structure = include_str!("../inputs/example").trim();
+
+    // Simulate the number of sand pieces it takes to fill up a cave with a floor described in the example input from the question
+    let total_sand_count = part_two(example_cave_structure);
+
+    // Check if the example yields the same result as the question describes
+    assert_eq!(total_sand_count, 93);
+}
+
+/// Generate a cave map with a floor and return the number pieces of sand it takes to fill up the location where the sand is pouring in
+fn part_two(cave_structure: &str) -> u32 {
+    // Parse the cave_structure input into a vector of rock paths
+    let rock_structures = parse_cave_structure(cave_structure);
+
+    // Define the coordinate of the sand source of where sand is spilling in from
+    let sand_source = Coord { x: 500, y: 0 };
+
+    // Generate the cave map given the rock structures and the location of the sand source
+    let mut cave_map = generate_cave_map(rock_structures, sand_source);
+
+    // The floor is 2 below the lowest rock, and since the cave map grows it can go on forever
+    let floor_y = cave_map.bounds().expect("The cave map is empty").max.y + 2;
+
+    // Render the cave map
+    // println!("The cave_map looks like:\n{cave_map}");
+
+    // * Animation
+    // Clear the screen
+    // print!("\x1B[2J\x1B[1;1H");
+
+    // Simulate sand falling again, but with a new end condition...
+    let mut resting_sand_count = 0;
+    loop {
+        // Simulate the particle falling and retrieve its final position
+        let Some(sand_position) = simulate_sand_particle_falling(&cave_map, sand_source, Some(floor_y)) else {
+            panic!("There shouldn't be any sand particles that fall outside the cave in part two...");
+        };
+
+        // If sand comes to a stop, increment the count of units of sand that have come to a rest and place the sand in its final location
+        resting_sand_count += 1;
+        cave_map.set(sand_position, 'o');
+
+        // If this particle of sand ends up in the sand source position, the source is now blocked, and we are done
+        if sand_position == sand_source {
+            break;
+        }
+
+        // * Animate: print the cave_map
+        // animate_cave_map(&cave_map);
+    }
+
+    // Return the count of the grains of sand that have come to rest
+    resting_sand_count
+}
+
+// endregion
@@ -1,10 +1,7 @@
 use std::{iter, thread, time::Duration};
 
-use ::day15_beacon_exclusion_zone::{
-    coordinate::Coord,
-    parse_sensor_data,
-    worldmap::{IndexResult, WorldMap},
-};
+use ::day15_beacon_exclusion_zone::{coordinate::Coord, parse_sensor_data, render_checkered, WorldMap};
+use aoc_common::grid::GridIndexError;
 
 fn main() {
     // Read in the example sensor data trimming any surrounding whitespace
@@ -14,10 +11,12 @@ fn main() {
     let sensor_data = parse_sensor_data(example_sensor_data);
 
     // Generate the full sensor map, animating it!
-    generate_world_map(&sensor_data, true);
+    if let Err(error) = generate_world_map(&sensor_data, true) {
+        eprintln!("Failed to draw the sensor map: {error}");
+    }
 }
 
-fn generate_world_map(sensor_data: &Vec<(Coord, Coord, u32)>, animation: bool) -> WorldMap {
+fn generate_world_map(sensor_data: &[(Coord, Coord, u32)], animation: bool) -> Result<WorldMap, GridIndexError> {
     println!("Getting min/maxes...");
     // Determine the x and y ranges that we are working with for these sensors & beacons
     let max_x = sensor_data
@@ -51,7 +50,7 @@ fn generate_world_map(sensor_data: &Vec<(Coord, Coord, u32)>, animation: bool) -
     println!("Height is: {height}, width is {width}");
 
     // Create the sensor map, initially filling it with air
-    let mut sensor_map = WorldMap::new(height, width, '.', Coord { x: min_x, y: min_y });
+    let mut sensor_map = WorldMap::new(width, height, '.', Coord { x: min_x, y: min_y });
 
     // * Animation
     if animation {
@@ -63,10 +62,10 @@ fn generate_world_map(sensor_data: &Vec<(Coord, Coord, u32)>, animation: bool) -
     for (sensor_coord, beacon_coord, distance) in sensor_data {
         // println!("Processing sensor: {:?}", *sensor_coord);
         // Add the sensor
-        *sensor_map.index_mut(*sensor_coord).unwrap() = 'S';
+        *sensor_map.get_mut(*sensor_coord)? = 'S';
 
         // Add the beacon
-        *sensor_map.index_mut(*beacon_coord).unwrap() = 'B';
+        *sensor_map.get_mut(*beacon_coord)? = 'B';
 
         // Fill in the positions that the sensor diamond blocks beacons from being
         for radius in 1..(*distance + 1) {
@@ -74,8 +73,9 @@ fn generate_world_map(sensor_data: &Vec<(Coord, Coord, u32)>, animation: bool) -
 
             // Could do some cool animations here...
             for position in diamond_iter {
-                if *sensor_map.index(position).unwrap() == '.' {
-                    *sensor_map.index_mut(position).unwrap() = '#';
+                let cell = sensor_map.get_mut(position)?;
+                if *cell == '.' {
+                    *cell = '#';
                 }
             }
 
@@ -86,7 +86,7 @@ fn generate_world_map(sensor_data: &Vec<(Coord, Coord, u32)>, animation: bool) -
     }
 
     // Return the generated sensor map
-    sensor_map
+    Ok(sensor_map)
 }
 
 fn get_diamond(center: Coord, radius: u32) -> impl Iterator<Item = Coord> {
@@ -103,13 +103,9 @@ fn get_diamond(center: Coord, radius: u32) -> impl Iterator<Item = Coord> {
     // Note: For the given order of side directions I selected, the start position will be at the top (visually) of the diamond
     side_directions
         .into_iter()
-        .flat_map(move |direction| iter::repeat(direction).take(num_steps_a_side))
+        .flat_map(move |direction| iter::repeat_n(direction, num_steps_a_side))
         .scan(
-            center
-                + Coord {
-                    x: 0,
-                    y: -1 * radius as i32,
-                },
+            center - Coord::new(0, radius as i32),
             |position, direction| {
                 // Move the position in the direction
                 *position += direction;
@@ -125,7 +121,7 @@ fn animate_sensor_map(sensor_map: &WorldMap) {
     print!("\x1B[1;1H");
 
     // Print the sensor map
-    println!("{}", sensor_map.render(true));
+    println!("{}", render_checkered(sensor_map));
 
     // Sleep for a certain amount of time to create a visible framerate in the animation
     thread::sleep(Duration::from_secs_f32(0.05));
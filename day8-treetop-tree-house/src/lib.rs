@@ -0,0 +1,191 @@
+use std::iter;
+
+use aoc_common::{solution::{Input, Solution}, Grid, Vec2};
+
+// region: Solution
+
+/// Day 8, for the runner
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Params = ();
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn input(input: Input) -> &'static str {
+        match input {
+            Input::Example => include_str!("../inputs/example").trim(),
+            Input::Question => include_str!("../inputs/question").trim(),
+        }
+    }
+
+    fn params(_: Input) {}
+
+    fn part_one(input: &str, _: &()) -> u32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str, _: &()) -> u32 {
+        part_two(input)
+    }
+
+    fn example_answers() -> (u32, u32) {
+        (21, 8)
+    }
+}
+
+// endregion
+
+// region: Helpers
+
+/// The grid of tree heights, with the top left tree at (0, 0)
+type TreeHeights = Grid<u8>;
+
+/// Parse the tree grid input into the respective tree heights
+fn parse_tree_heights(tree_heights_str: &str) -> TreeHeights {
+    Grid::parse(tree_heights_str, |char_num| char_num.to_digit(10).map(|height| height as u8))
+        .expect("Non-number height in input")
+}
+
+fn tree_directional_map<F>(coordinate: Vec2<i32>, tree_heights: &TreeHeights, map_fn: F) -> Vec<u32>
+where
+    F: Fn(u8, Vec<u8>) -> u32, {
+    // Get the height of the current tree
+    let current_tree_height = *tree_heights.get(coordinate).unwrap_or_else(|error| panic!("{error}"));
+
+    // I feel like a logical extension for part two would be diagonal visibility as well, so I'll make it this way in case :)
+    // Check up, down, left, right
+    let directions = [(0, 1), (0, -1), (-1, 0), (1, 0)].map(Vec2::from);
+
+    // For each direction from the tree, map the iterator in that direction to a number
+    directions
+        .iter()
+        .map(|direction| {
+            // Add the state to be used by a direction iterator
+            let mut position = coordinate;
+            // Construct an iterator that yields the heights of the trees in a given direction
+            let direction_iter = iter::from_fn(|| {
+                // Update the position of what the next tree in the iter would be
+                position += *direction;
+
+                // Get the height of the next tree, passing along None to the iter once it goes off the edge of the grid
+                tree_heights.get(position).ok().copied()
+            }).collect::<Vec<u8>>();
+
+            // Return the value the map_function for the given direction
+            map_fn(current_tree_height, direction_iter)
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Given the grid of `tree_heights`, evaluate each tree's ordinal surroundings with the evaluating it by the given `evaluate_fn`
+fn evaluate_tree_surroundings<F>(tree_heights: &TreeHeights, evaluate_fn: F) -> Grid<u32>
+    where F: Fn(Vec2<i32>, &TreeHeights) -> u32 {
+    // Determine if the tree at each coordinate satisfies the evaluate_fn
+    tree_heights.map(|coordinate, _| evaluate_fn(coordinate, tree_heights))
+}
+
+// endregion
+
+// region: Part One
+
+#[test]
+fn part_one_example_test() {
+    // Read in the example tree heights trimming any surrounding whitespace
+    let example_tree_heights = include_str!("../inputs/example").trim();
+
+    // Count the number of visible trees from in the example grid input from the question
+    let visible_tree_count = part_one(example_tree_heights);
+
+    // Check if the example yields the same result as the question describes
+    assert_eq!(visible_tree_count, 21);
+}
+
+/// Count the number of trees in the grid that are visible from outside the grid
+fn part_one(tree_heights_input: &str) -> u32 {
+    // Parse the heights of the trees as u8 from the input tree grid
+    let tree_heights = parse_tree_heights(tree_heights_input);
+
+    // Evaluate each tree on whether it is visible from the outside
+    let visibility = evaluate_tree_surroundings(&tree_heights, tree_is_visible);
+
+    // Sum the number of visible trees
+    visibility.cells().sum()
+}
+
+/// Return the visibility of the tree at `coordinate` from the edge from any cardinal direction as an int
+fn tree_is_visible(coordinate: Vec2<i32>, tree_heights: &TreeHeights) -> u32 {
+    // Traverse the surrounding trees
+    let direction_visibility = tree_directional_map(coordinate, tree_heights, tree_visibile_in_direction);
+
+    // The tree is visible if it is visible from any of the directions
+    // Note: since 'any' is short-circuiting, it would be better performance-wise to not collect it into a vector above and just call any
+    //  on the initial iter. I like the readability here though, and it might make more sense depening on the requirements from part two
+    direction_visibility.contains(&1) as u32
+}
+
+/// Return whether the tree is visible from this direction by checking if there arent
+///  any trees obstructing it (that is, taller than or equal to it)
+/// 
+/// Doing it this way also works for edges as well as `any_obstructing` will be false if there is nothing in that direction
+fn tree_visibile_in_direction(current_tree_height: u8, direction_heights: Vec<u8>) -> u32 {
+    let any_obstructing = direction_heights
+        .iter()
+        .any(|tree_height| *tree_height >= current_tree_height);
+    !any_obstructing as u32
+}
+
+// endregion
+
+// region: Part Two
+
+#[test]
+fn part_two_example_test() {
+    // Read in the example tree heights trimming any surrounding whitespace
+    let example_tree_heights = include_str!("../inputs/example").trim();
+
+    // Find the maximum scenic score among the trees in the example tree grid input from the question
+    let max_scenic_score = part_two(example_tree_heights);
+
+    // Check if the example yields the same result as the question describes
+    assert_eq!(max_scenic_score, 8);
+}
+
+/// Find the maximum scenic score among all of the trees in the grid
+fn part_two(tree_heights_input: &str) -> u32 {
+    // Parse the heights of the trees as u8 from the input tree grid
+    let tree_heights = parse_tree_heights(tree_heights_input);
+
+    // Evaluate each tree on what its scenic score is
+    let scenic_scores = evaluate_tree_surroundings(&tree_heights, tree_scenic_score);
+
+    // Find the maximum scenic score
+    *scenic_scores.cells().max().expect("Failed to find a max scenic score...")
+}
+
+/// Calculate the scenic score of the tree at `coordinate` considering each cardinal direction
+fn tree_scenic_score(coordinate: Vec2<i32>, tree_heights: &TreeHeights) -> u32 {
+    // Traverse the surrounding trees
+    let direction_scores = tree_directional_map(coordinate, tree_heights, tree_scenic_score_in_direction);
+
+    // Get the total scenic score as the product of each direction's score
+    direction_scores.iter().product()
+}
+
+/// Calculate the driectional scenic score for a tree of height `current_tree_height` using the heights of the trees in the given direction 
+fn tree_scenic_score_in_direction(current_tree_height: u8, direction_heights: Vec<u8>) -> u32 {
+    let mut score = 0;
+    for tree_height in direction_heights {
+        // If our view is not yet obstructed, increment the scenic score for this tree
+        score += 1;
+        // If this tree obstructs the view, we won't be able to see any more
+        if tree_height >= current_tree_height { 
+            break;
+        }
+    }
+    // Return the resulting scenic score
+    score
+}
+
+// endregion